@@ -0,0 +1,116 @@
+use std::{collections::HashMap, thread, time::Instant};
+
+use glam::Vec3;
+
+use marching_cubes::march_tables::{EDGES, POINTS, TRIANGULATIONS};
+use marching_cubes::{dual_contouring::dual_contour, marching_cubes, marching_cubes_parallel, marching_tetrahedra::marching_tetrahedra, surface_nets::surface_net, Grid, Isolevel, MeshData, Normals, Topology, VoxelGrid};
+
+// Same chunk size as the showcase.
+const RES: usize = 64;
+const RUNS: u32 = 20;
+
+// Cheap metaball field standing in for the Perlin noise used by the
+// showcase, so that the timings are dominated by meshing, not sampling.
+fn field(x: f32, y: f32, z: f32) -> f32 {
+    const BALLS: [[f32; 4]; 6] = [
+        [0.2, 0.3, 0.4, 0.02],
+        [0.7, 0.2, 0.6, 0.03],
+        [0.5, 0.7, 0.3, 0.025],
+        [0.3, 0.8, 0.8, 0.015],
+        [0.8, 0.6, 0.2, 0.02],
+        [0.5, 0.5, 0.9, 0.01],
+    ];
+
+    let (x, y, z) = (x / RES as f32, y / RES as f32, z / RES as f32);
+
+    0.5 - BALLS.iter().map(|[bx, by, bz, r]| {
+        r / ((x - bx) * (x - bx) + (y - by) * (y - by) + (z - bz) * (z - bz))
+    }).sum::<f32>()
+}
+
+// Marching cubes the way it was before the slab caches, with the vertices
+// welded through a map of doubled edge-midpoint coordinates.
+fn marching_cubes_map(grid: Grid, isolevel: Isolevel) -> MeshData {
+    let voxels = VoxelGrid::from_scalar_field(grid, &field);
+
+    let mut positions = Vec::new();
+    let mut indices = Vec::new();
+    let mut vertices = HashMap::new();
+
+    for z in 0..grid.size[2] {
+        for y in 0..grid.size[1] {
+            for x in 0..grid.size[0] {
+                let config = (0..8).fold(0, |config, i| {
+                    let (x0, y0, z0) = POINTS[i];
+                    config | (isolevel.is_inside(voxels.read(x + x0, y + y0, z + z0)) as usize) << i
+                });
+
+                for &edge_index in TRIANGULATIONS[config].iter().take_while(|e| !e.is_negative()) {
+                    let (a, b) = EDGES[edge_index as usize];
+                    let ((x0, y0, z0), (x1, y1, z1)) = (POINTS[a], POINTS[b]);
+
+                    let key = [x * 2 + x0 + x1, y * 2 + y0 + y1, z * 2 + z0 + z1];
+
+                    let index = *vertices.entry(key).or_insert_with(|| {
+                        let pos_a = Vec3::new((x + x0) as f32, (y + y0) as f32, (z + z0) as f32);
+                        let pos_b = Vec3::new((x + x1) as f32, (y + y1) as f32, (z + z1) as f32);
+
+                        let t = isolevel.crossing(voxels.read(x + x0, y + y0, z + z0), voxels.read(x + x1, y + y1, z + z1));
+
+                        positions.push(grid.to_world((pos_a + (pos_b - pos_a) * t).into()));
+                        positions.len() as u32 - 1
+                    });
+
+                    indices.push(index);
+                }
+            }
+        }
+    }
+
+    let mut normals = vec![Vec3::ZERO; positions.len()];
+
+    for triangle in indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|k| Vec3::from(positions[triangle[k] as usize]));
+        let n = (c - a).cross(b - a);
+
+        for &i in triangle {
+            normals[i as usize] += n;
+        }
+    }
+
+    let normals = normals.into_iter().map(|n| n.normalize().into()).collect();
+
+    MeshData::new(positions, normals, indices)
+}
+
+// Returns the time per run in milliseconds.
+fn bench(name: &str, mesher: impl Fn() -> MeshData) -> f64 {
+    let mesh_data = mesher();
+
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        mesher();
+    }
+    let elapsed = start.elapsed().as_secs_f64() * 1000.0 / RUNS as f64;
+
+    println!(
+        "{name}: {elapsed:.2}ms per {RES}^3 chunk ({} vertices, {} triangles)",
+        mesh_data.vertex_count(),
+        mesh_data.triangle_count(),
+    );
+
+    elapsed
+}
+
+fn main() {
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+
+    let old = bench("marching_cubes_map", || marching_cubes_map(Grid::cube(RES), Isolevel::default()));
+    let new = bench("marching_cubes", || marching_cubes(Grid::cube(RES), &field, Isolevel::default(), Topology::Classic, Normals::Area));
+    println!("slab caches: {old:.2}ms before, {new:.2}ms after, {:.2}x as fast", old / new);
+
+    bench("marching_cubes_parallel", || marching_cubes_parallel(Grid::cube(RES), &field, Isolevel::default(), Topology::Classic, Normals::Area, threads));
+    bench("marching_tetrahedra", || marching_tetrahedra(Grid::cube(RES), &field, Isolevel::default(), Normals::Area));
+    bench("surface_net", || surface_net(Grid::cube(RES), &field, Isolevel::default()));
+    bench("dual_contour", || dual_contour(Grid::cube(RES), &field, Isolevel::default()));
+}
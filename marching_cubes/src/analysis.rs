@@ -0,0 +1,172 @@
+use std::collections::BTreeMap;
+
+use glam::Vec3;
+
+use crate::MeshData;
+
+// What is wrong with the topology and triangles of a mesh, if anything.
+// Edges are given by their two vertex indices, smaller first, and
+// triangles by their position in the index list.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MeshReport {
+    // Edges with a single triangle on them, where the surface is open.
+    pub boundary_edges: Vec<[u32; 2]>,
+    // Edges with more than two triangles on them.
+    pub non_manifold_edges: Vec<[u32; 2]>,
+    // Vertices where separate sheets of triangles touch, such as the tip
+    // of two cones, so that the triangles around them don't form a single
+    // fan.
+    pub non_manifold_vertices: Vec<u32>,
+    // Edges between two triangles that run along them in the same
+    // direction, so that one of them is wound the other way round.
+    pub misoriented_edges: Vec<[u32; 2]>,
+    // Triangles that use the same vertex more than once. They are left out
+    // of everything else.
+    pub degenerate_triangles: Vec<usize>,
+    // Triangles on three different vertices that still have no area, as
+    // their positions coincide or lie on a line.
+    pub zero_area_triangles: Vec<usize>,
+    // Number of pieces of the surface, joined wherever triangles share a
+    // vertex.
+    pub components: usize,
+    // Vertices minus edges plus triangles, counting only the vertices that
+    // triangles use. Two for every closed piece the shape of a sphere, two
+    // less for every handle.
+    pub euler_characteristic: i64,
+}
+
+impl MeshReport {
+    pub fn is_closed(&self) -> bool {
+        self.boundary_edges.is_empty()
+    }
+
+    pub fn is_manifold(&self) -> bool {
+        self.non_manifold_edges.is_empty() && self.non_manifold_vertices.is_empty()
+    }
+
+    pub fn is_oriented(&self) -> bool {
+        self.misoriented_edges.is_empty()
+    }
+
+    // Closed, manifold and consistently wound, so that the mesh bounds a
+    // solid, as 3D printing and physics engines expect.
+    pub fn is_watertight(&self) -> bool {
+        self.is_closed() && self.is_manifold() && self.is_oriented()
+    }
+
+    // Watertight, without any triangles that have no area.
+    pub fn is_valid(&self) -> bool {
+        self.is_watertight() && self.degenerate_triangles.is_empty() && self.zero_area_triangles.is_empty()
+    }
+}
+
+// Checks the topology of an indexed mesh. Vertices are told apart by their
+// index only, so meshes with separate vertices at the same position, such
+// as from `marching_cubes_disjointed`, show up as open.
+pub fn analyze(mesh: &MeshData) -> MeshReport {
+    let mut report = MeshReport::default();
+    let mut triangles = Vec::new();
+
+    for (t, triangle) in mesh.indices.chunks_exact(3).enumerate() {
+        let [a, b, c] = [triangle[0], triangle[1], triangle[2]];
+
+        if a == b || b == c || c == a {
+            report.degenerate_triangles.push(t);
+            continue;
+        }
+
+        let [pa, pb, pc] = [a, b, c].map(|v| Vec3::from(mesh.positions[v as usize]));
+
+        if (pc - pa).cross(pb - pa) == Vec3::ZERO {
+            report.zero_area_triangles.push(t);
+        }
+
+        triangles.push([a, b, c]);
+    }
+
+    // How often each edge is run along in either direction.
+    let mut edges: BTreeMap<[u32; 2], [usize; 2]> = BTreeMap::new();
+
+    for triangle in &triangles {
+        for k in 0..3 {
+            let (a, b) = (triangle[k], triangle[(k + 1) % 3]);
+            edges.entry([a.min(b), a.max(b)]).or_default()[(a > b) as usize] += 1;
+        }
+    }
+
+    for (&edge, &[forward, backward]) in &edges {
+        match forward + backward {
+            1 => report.boundary_edges.push(edge),
+            2 if forward != backward => report.misoriented_edges.push(edge),
+            2 => {}
+            _ => report.non_manifold_edges.push(edge),
+        }
+    }
+
+    let mut vertex_triangles = vec![Vec::new(); mesh.vertex_count()];
+
+    for (t, triangle) in triangles.iter().enumerate() {
+        for &v in triangle {
+            vertex_triangles[v as usize].push(t);
+        }
+    }
+
+    for (v, around) in vertex_triangles.iter().enumerate() {
+        if fan_count(v as u32, around, &triangles) > 1 {
+            report.non_manifold_vertices.push(v as u32);
+        }
+    }
+
+    let used = vertex_triangles.iter().filter(|around| !around.is_empty()).count();
+    report.euler_characteristic = used as i64 - edges.len() as i64 + triangles.len() as i64;
+
+    let mut parents: Vec<usize> = (0..mesh.vertex_count()).collect();
+
+    for triangle in &triangles {
+        union(&mut parents, triangle[0] as usize, triangle[1] as usize);
+        union(&mut parents, triangle[0] as usize, triangle[2] as usize);
+    }
+
+    report.components = (0..mesh.vertex_count())
+        .filter(|&v| !vertex_triangles[v].is_empty() && find(&mut parents, v) == v)
+        .count();
+
+    report
+}
+
+// Number of groups the triangles around `vertex` fall into, joining the
+// ones that share an edge from it.
+fn fan_count(vertex: u32, around: &[usize], triangles: &[[u32; 3]]) -> usize {
+    let mut parents: Vec<usize> = (0..around.len()).collect();
+
+    for i in 0..around.len() {
+        for j in i + 1..around.len() {
+            let shared = triangles[around[i]]
+                .iter()
+                .filter(|&&w| w != vertex && triangles[around[j]].contains(&w))
+                .count();
+
+            if shared > 0 {
+                union(&mut parents, i, j);
+            }
+        }
+    }
+
+    (0..around.len()).filter(|&i| find(&mut parents, i) == i).count()
+}
+
+fn find(parents: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+
+    while parents[root] != root {
+        root = parents[root];
+    }
+
+    parents[i] = root;
+    root
+}
+
+fn union(parents: &mut [usize], a: usize, b: usize) {
+    let (a, b) = (find(parents, a), find(parents, b));
+    parents[a] = b;
+}
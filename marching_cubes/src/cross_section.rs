@@ -0,0 +1,84 @@
+use glam::Vec3;
+
+use crate::marching_squares::{contour_lines, Ambiguity, Grid2d, PixelGrid};
+use crate::{Isolevel, ScalarField};
+
+// A plane through `origin`, spanned by the directions `u` and `v`. Plane
+// coordinates (a, b) are at `origin + u * a + v * b` in world space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Plane {
+    pub origin: [f32; 3],
+    pub u: [f32; 3],
+    pub v: [f32; 3],
+}
+
+impl Plane {
+    pub fn new(origin: [f32; 3], u: [f32; 3], v: [f32; 3]) -> Self {
+        Self { origin, u, v }
+    }
+
+    // A plane through `origin` facing `normal`, with unit directions
+    // picked such that u × v = normal.
+    pub fn from_normal(origin: [f32; 3], normal: [f32; 3]) -> Self {
+        let normal = Vec3::from(normal).normalize();
+        let u = normal.any_orthogonal_vector().normalize();
+
+        Self::new(origin, u.into(), normal.cross(u).into())
+    }
+
+    // Maps plane coordinates to world space.
+    pub fn to_world(&self, [a, b]: [f32; 2]) -> [f32; 3] {
+        (Vec3::from(self.origin) + Vec3::from(self.u) * a + Vec3::from(self.v) * b).into()
+    }
+}
+
+// A contour line of a cross-section, in world space. Seen from the side
+// u × v of its plane points to, the inside lies to its left.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Polyline3d {
+    pub points: Vec<[f32; 3]>,
+    pub closed: bool,
+}
+
+// The values of `scalar_field` on the points of `grid`, which is laid out
+// in plane coordinates. Only the plane is sampled.
+pub fn sample_plane(plane: Plane, grid: Grid2d, scalar_field: &(impl ScalarField + ?Sized)) -> PixelGrid {
+    let [size_a, size_b] = grid.dimensions();
+
+    let data = (0..size_a * size_b)
+        .map(|i| {
+            let [x, y, z] = plane.to_world(grid.to_world([(i % size_a) as f32, (i / size_a) as f32]));
+            scalar_field.sample(x, y, z)
+        })
+        .collect();
+
+    PixelGrid::new(grid, data)
+}
+
+// The contour lines of an image taken by `sample_plane`, in world space.
+pub fn section_lines(
+    plane: Plane,
+    image: &PixelGrid,
+    isolevel: Isolevel,
+    ambiguity: Ambiguity,
+) -> Vec<Polyline3d> {
+    contour_lines(image, isolevel, ambiguity)
+        .into_iter()
+        .map(|polyline| Polyline3d {
+            points: polyline.points.into_iter().map(|point| plane.to_world(point)).collect(),
+            closed: polyline.closed,
+        })
+        .collect()
+}
+
+// Where the surface `marching_cubes` would extract crosses the part of
+// `plane` covered by `grid`, without meshing the volume.
+pub fn cross_section(
+    plane: Plane,
+    grid: Grid2d,
+    scalar_field: &(impl ScalarField + ?Sized),
+    isolevel: Isolevel,
+    ambiguity: Ambiguity,
+) -> Vec<Polyline3d> {
+    section_lines(plane, &sample_plane(plane, grid, scalar_field), isolevel, ambiguity)
+}
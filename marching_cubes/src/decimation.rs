@@ -0,0 +1,384 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+use glam::{DMat3, DVec3, Vec3};
+
+use crate::mesh_data::Attribute;
+use crate::MeshData;
+
+// When to stop collapsing edges: once the mesh is down to
+// `target_triangles`, or once the cheapest collapse left would move the
+// surface by more than `max_error`. The error of a vertex is the sum of
+// its squared distances to the planes of the original triangles merged
+// into it, in world units squared.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Decimation {
+    pub target_triangles: usize,
+    pub max_error: f32,
+}
+
+impl Decimation {
+    pub fn new(target_triangles: usize) -> Self {
+        Self { target_triangles, max_error: f32::INFINITY }
+    }
+
+    pub fn with_max_error(mut self, max_error: f32) -> Self {
+        self.max_error = max_error;
+        self
+    }
+}
+
+// Simplifies a mesh by collapsing its edges, cheapest first by the
+// quadric error metric (Garland and Heckbert, 1997). Vertices on the
+// border of an open mesh, such as where the surface leaves a chunk, never
+// move and are never removed, so neighbouring chunks decimated on their
+// own still meet. Collapses that would turn a triangle over, or pinch the
+// mesh into a non-manifold, are skipped.
+//
+// Remaining vertices keep their normal and attributes, except that the
+// normal of a moved vertex is blended with the one of the vertex merged
+// into it.
+pub fn decimate(mesh: &MeshData, decimation: Decimation) -> MeshData {
+    let mut decimator = Decimator::new(mesh);
+    decimator.run(decimation);
+    decimator.finish(mesh)
+}
+
+struct Decimator {
+    positions: Vec<DVec3>,
+    normals: Vec<Vec3>,
+    quadrics: Vec<Quadric>,
+    triangles: Vec<[u32; 3]>,
+    removed: Vec<bool>,
+    // Triangles around every vertex, removed ones included until the
+    // vertex changes.
+    vertex_triangles: Vec<Vec<usize>>,
+    border: Vec<bool>,
+    // Bumped whenever a vertex moves or is merged away, to tell queued
+    // collapses of its edges are stale.
+    versions: Vec<u32>,
+    merged: Vec<bool>,
+    triangle_count: usize,
+    queue: BinaryHeap<Collapse>,
+}
+
+impl Decimator {
+    fn new(mesh: &MeshData) -> Self {
+        let positions: Vec<DVec3> = mesh.positions.iter().map(|&p| Vec3::from(p).as_dvec3()).collect();
+        let triangles: Vec<[u32; 3]> = mesh.indices.chunks_exact(3).map(|t| [t[0], t[1], t[2]]).collect();
+
+        let mut quadrics = vec![Quadric::default(); positions.len()];
+        let mut vertex_triangles = vec![Vec::new(); positions.len()];
+        let mut edge_uses: HashMap<(u32, u32), usize> = HashMap::new();
+
+        for (t, triangle) in triangles.iter().enumerate() {
+            let quadric = Quadric::plane(triangle.map(|i| positions[i as usize]));
+
+            for k in 0..3 {
+                let (a, b) = (triangle[k], triangle[(k + 1) % 3]);
+                *edge_uses.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+
+                quadrics[a as usize] += quadric;
+                vertex_triangles[a as usize].push(t);
+            }
+        }
+
+        let mut border = vec![false; positions.len()];
+
+        for (&(a, b), &uses) in &edge_uses {
+            if uses != 2 {
+                border[a as usize] = true;
+                border[b as usize] = true;
+            }
+        }
+
+        let mut decimator = Self {
+            normals: mesh.normals.iter().map(|&n| Vec3::from(n)).collect(),
+            merged: vec![false; positions.len()],
+            versions: vec![0; positions.len()],
+            removed: vec![false; triangles.len()],
+            triangle_count: triangles.len(),
+            positions,
+            quadrics,
+            triangles,
+            vertex_triangles,
+            border,
+            queue: BinaryHeap::new(),
+        };
+
+        let mut edges: Vec<(u32, u32)> = edge_uses.into_keys().collect();
+        edges.sort_unstable();
+
+        for (a, b) in edges {
+            decimator.queue_collapse(a, b);
+        }
+
+        decimator
+    }
+
+    fn run(&mut self, decimation: Decimation) {
+        while self.triangle_count > decimation.target_triangles {
+            let Some(collapse) = self.queue.pop() else { break };
+
+            if collapse.versions != [self.versions[collapse.keep as usize], self.versions[collapse.remove as usize]] {
+                continue;
+            }
+
+            if collapse.error > decimation.max_error as f64 {
+                break;
+            }
+
+            if self.can_collapse(collapse.keep, collapse.remove, collapse.position) {
+                self.collapse(collapse.keep, collapse.remove, collapse.position);
+            }
+        }
+    }
+
+    fn queue_collapse(&mut self, a: u32, b: u32) {
+        let (keep, remove) = match (self.border[a as usize], self.border[b as usize]) {
+            (true, true) => return,
+            (false, true) => (b, a),
+            _ => (a, b),
+        };
+
+        let quadric = self.quadrics[keep as usize] + self.quadrics[remove as usize];
+        let ends = [self.positions[keep as usize], self.positions[remove as usize]];
+        let middle = (ends[0] + ends[1]) / 2.0;
+
+        // Border vertices stay where they are. Elsewhere, the point closest
+        // to all the planes, unless they are too close to parallel to pin
+        // down one near the edge.
+        let position = if self.border[keep as usize] {
+            ends[0]
+        } else {
+            quadric
+                .minimum()
+                .filter(|minimum| minimum.distance(middle) <= ends[0].distance(ends[1]))
+                .unwrap_or_else(|| {
+                    [ends[0], ends[1], middle]
+                        .into_iter()
+                        .min_by(|p, q| quadric.error(*p).total_cmp(&quadric.error(*q)))
+                        .unwrap()
+                })
+        };
+
+        // Collapsing onto the other end keeps the vertex that is already
+        // there, with its normal and attributes.
+        let (keep, remove) = if position == ends[1] && !self.border[keep as usize] { (remove, keep) } else { (keep, remove) };
+
+        self.queue.push(Collapse {
+            error: quadric.error(position).max(0.0),
+            keep,
+            remove,
+            position,
+            versions: [self.versions[keep as usize], self.versions[remove as usize]],
+        });
+    }
+
+    fn live_triangles(&self, vertex: u32) -> impl Iterator<Item = usize> + '_ {
+        self.vertex_triangles[vertex as usize].iter().copied().filter(|&t| !self.removed[t])
+    }
+
+    fn can_collapse(&self, keep: u32, remove: u32, position: DVec3) -> bool {
+        let neighbours = |vertex: u32| {
+            let mut neighbours: Vec<u32> = self.live_triangles(vertex).flat_map(|t| self.triangles[t]).filter(|&v| v != vertex).collect();
+            neighbours.sort_unstable();
+            neighbours.dedup();
+            neighbours
+        };
+
+        // The two vertices across the edge must be the only neighbours the
+        // ends share, or the collapse would fuse parts of the mesh.
+        let removed_neighbours = neighbours(remove);
+        let shared = neighbours(keep).into_iter().filter(|v| removed_neighbours.contains(v)).count();
+        let across = self.live_triangles(keep).filter(|&t| self.triangles[t].contains(&remove)).count();
+
+        if across != 2 || shared != 2 {
+            return false;
+        }
+
+        // The triangles that stay must keep facing the same way.
+        for vertex in [keep, remove] {
+            for t in self.live_triangles(vertex) {
+                let triangle = self.triangles[t];
+
+                if triangle.contains(&keep) && triangle.contains(&remove) {
+                    continue;
+                }
+
+                let before = triangle.map(|v| self.positions[v as usize]);
+                let after = triangle.map(|v| if v == keep || v == remove { position } else { self.positions[v as usize] });
+
+                if normal(after).dot(normal(before)) <= 0.0 {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    fn collapse(&mut self, keep: u32, remove: u32, position: DVec3) {
+        let moved = position != self.positions[keep as usize];
+
+        self.positions[keep as usize] = position;
+        self.quadrics[keep as usize] = self.quadrics[keep as usize] + self.quadrics[remove as usize];
+
+        if moved && !self.normals.is_empty() {
+            self.normals[keep as usize] = (self.normals[keep as usize] + self.normals[remove as usize]).normalize_or_zero();
+        }
+
+        let triangles: Vec<usize> = self.live_triangles(remove).collect();
+
+        for t in triangles {
+            if self.triangles[t].contains(&keep) {
+                self.removed[t] = true;
+                self.triangle_count -= 1;
+            } else {
+                for vertex in self.triangles[t].iter_mut() {
+                    if *vertex == remove {
+                        *vertex = keep;
+                    }
+                }
+
+                self.vertex_triangles[keep as usize].push(t);
+            }
+        }
+
+        self.merged[remove as usize] = true;
+        self.versions[remove as usize] += 1;
+        self.versions[keep as usize] += 1;
+
+        let live: Vec<usize> = self.live_triangles(keep).collect();
+        self.vertex_triangles[keep as usize] = live;
+
+        let mut neighbours: Vec<u32> = self.vertex_triangles[keep as usize].iter().flat_map(|&t| self.triangles[t]).filter(|&v| v != keep).collect();
+        neighbours.sort_unstable();
+        neighbours.dedup();
+
+        for neighbour in neighbours {
+            self.queue_collapse(keep, neighbour);
+        }
+    }
+
+    fn finish(self, mesh: &MeshData) -> MeshData {
+        let kept: Vec<usize> = (0..self.positions.len()).filter(|&v| !self.merged[v]).collect();
+
+        let mut new_index = vec![u32::MAX; self.positions.len()];
+
+        for (i, &v) in kept.iter().enumerate() {
+            new_index[v] = i as u32;
+        }
+
+        let indices = self.triangles
+            .iter()
+            .zip(&self.removed)
+            .filter(|(_, &removed)| !removed)
+            .flat_map(|(triangle, _)| triangle.map(|v| new_index[v as usize]))
+            .collect();
+
+        let positions = kept.iter().map(|&v| self.positions[v].as_vec3().into()).collect();
+        let normals = kept.iter().filter_map(|&v| self.normals.get(v)).map(|&n| n.into()).collect();
+
+        let mut decimated = MeshData::new(positions, normals, indices);
+
+        for attribute in &mesh.attributes {
+            decimated.attributes.push(Attribute { values: attribute.values.select(&kept), ..attribute.clone() });
+        }
+
+        decimated
+    }
+}
+
+fn normal([a, b, c]: [DVec3; 3]) -> DVec3 {
+    (c - a).cross(b - a)
+}
+
+// Sum of squared distances to a set of planes, as the symmetric matrix
+// `a`, vector `b` and constant `c` of `p·ap + 2b·p + c`.
+#[derive(Clone, Copy)]
+struct Quadric {
+    a: DMat3,
+    b: DVec3,
+    c: f64,
+}
+
+impl Quadric {
+    // The plane of a triangle. Degenerate triangles have none.
+    fn plane(corners: [DVec3; 3]) -> Self {
+        let n = normal(corners).normalize_or_zero();
+        let d = -n.dot(corners[0]);
+
+        Self {
+            a: DMat3::from_cols(n * n.x, n * n.y, n * n.z),
+            b: n * d,
+            c: d * d,
+        }
+    }
+
+    fn error(&self, p: DVec3) -> f64 {
+        p.dot(self.a * p) + 2.0 * self.b.dot(p) + self.c
+    }
+
+    // The point of least error, if there is a single one.
+    fn minimum(&self) -> Option<DVec3> {
+        let det = self.a.determinant();
+        let scale = self.a.x_axis.length_squared() + self.a.y_axis.length_squared() + self.a.z_axis.length_squared();
+
+        if det.abs() <= 1e-9 * scale.powf(1.5) {
+            return None;
+        }
+
+        Some(self.a.inverse() * -self.b)
+    }
+}
+
+// Not derived, as the default of a matrix is the identity.
+impl Default for Quadric {
+    fn default() -> Self {
+        Self { a: DMat3::ZERO, b: DVec3::ZERO, c: 0.0 }
+    }
+}
+
+impl std::ops::Add for Quadric {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self { a: self.a + other.a, b: self.b + other.b, c: self.c + other.c }
+    }
+}
+
+impl std::ops::AddAssign for Quadric {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+// A queued edge collapse, ordered cheapest first.
+struct Collapse {
+    error: f64,
+    keep: u32,
+    remove: u32,
+    position: DVec3,
+    versions: [u32; 2],
+}
+
+impl PartialEq for Collapse {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Collapse {}
+
+impl PartialOrd for Collapse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Collapse {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.error.total_cmp(&self.error).then_with(|| (other.keep, other.remove).cmp(&(self.keep, self.remove)))
+    }
+}
@@ -0,0 +1,232 @@
+use glam::{Mat3, Vec3};
+
+use crate::real::{self, Real};
+use crate::surface_nets::{contour_cells, find_edge, DiscreteScalarField, OFFSETS};
+use crate::{Grid, Isolevel, MeshData, ScalarField, VoxelGrid, Voxels};
+
+// Directions in which the planes of a cell differ less than this, relative
+// to the one they differ most in, don't move its vertex away from the
+// average of its crossings.
+const TRUNCATION: f32 = 0.01;
+
+// Like `surface_net`, but each vertex is placed where the tangent planes at
+// the crossings of its cell meet, so sharp edges and corners of the surface
+// are kept.
+pub fn dual_contour<T: Real>(
+    grid: Grid<T>,
+    scalar_field: &(impl ScalarField<T> + ?Sized),
+    isolevel: Isolevel<T>,
+) -> MeshData<T> {
+    let voxel_grid = VoxelGrid::from_scalar_field(grid, scalar_field);
+    let active_blocks = voxel_grid.min_max_pyramid().active_blocks(isolevel);
+
+    let discrete_scalar_field = &move |x, y, z| voxel_grid.read(x, y, z);
+
+    contour_cells(grid, discrete_scalar_field, &active_blocks, isolevel, |coord| {
+        find_vertex(discrete_scalar_field, scalar_field, grid, isolevel, coord)
+    })
+}
+
+fn find_vertex<T: Real>(
+    discrete_scalar_field: &DiscreteScalarField<T>,
+    scalar_field: &(impl ScalarField<T> + ?Sized),
+    grid: Grid<T>,
+    isolevel: Isolevel<T>,
+    coord: (usize, usize, usize),
+) -> Option<([T; 3], [f32; 3])> {
+    let values: [T; 8] = std::array::from_fn(|x| {
+        discrete_scalar_field(
+            coord.0 + ((x >> 2) & 1),
+            coord.1 + ((x >> 1) & 1),
+            coord.2 + (x & 1),
+        )
+    });
+
+    let origin = Grid::point([coord.0, coord.1, coord.2]);
+    let spacing = Vec3::from(grid.spacing.map(T::to_f32));
+
+    // Crossings relative to the cell, and the field gradients there. Both
+    // stay small wherever the cell is, so the planes are solved for in
+    // single precision.
+    let mut points = Vec::new();
+    let mut gradients = Vec::new();
+
+    for &(offset1, offset2) in &OFFSETS {
+        if let Some(point) = find_edge(isolevel, offset1, offset2, values[offset1], values[offset2]) {
+            let ends = (corner(offset1), corner(offset2));
+            let point = refine_crossing(scalar_field, grid, isolevel, origin, ends, (values[offset1], values[offset2]), point.map(T::to_f32).into());
+
+            points.push(point);
+            gradients.push(field_gradient(scalar_field, grid, cell_point(origin, point)));
+        }
+    }
+
+    if points.is_empty() {
+        return None;
+    }
+
+    let mass_point = points.iter().fold(Vec3::ZERO, |sum, &point| sum + point) / points.len() as f32;
+
+    // The planes are solved for in grid units, in which they are tilted by
+    // the spacing.
+    let planes: Vec<Vec3> = gradients.iter().map(|&gradient| (gradient * spacing).normalize_or_zero()).collect();
+    let vertex = solve_qef(&points, &planes, mass_point, 3).clamp(Vec3::ZERO, Vec3::ONE);
+
+    let normal = gradients.iter().fold(Vec3::ZERO, |sum, &gradient| sum + gradient.normalize_or_zero());
+
+    Some((grid.to_world(cell_point(origin, vertex)), orient_normal(isolevel, normal)))
+}
+
+fn corner(offset: usize) -> Vec3 {
+    Vec3::new(((offset >> 2) & 1) as f32, ((offset >> 1) & 1) as f32, (offset & 1) as f32)
+}
+
+// The grid coordinate of a point relative to the cell at `origin`.
+pub(crate) fn cell_point<T: Real>(origin: [T; 3], point: Vec3) -> [T; 3] {
+    real::add(origin, point.to_array().map(T::from_f32))
+}
+
+// A sum of gradients as a unit normal pointing away from the inside.
+pub(crate) fn orient_normal<T: Real>(isolevel: Isolevel<T>, normal: Vec3) -> [f32; 3] {
+    Vec3::from(isolevel.orient(normal.to_array().map(T::from_f32)).map(T::to_f32)).normalize_or_zero().into()
+}
+
+// Interpolating the samples only finds crossings exactly where the field
+// is linear, which it isn't near the features the planes are meant to
+// find. Moves a crossing onto the field itself, with a few steps of the
+// Illinois variant of false position.
+pub(crate) fn refine_crossing<T: Real>(
+    scalar_field: &(impl ScalarField<T> + ?Sized),
+    grid: Grid<T>,
+    isolevel: Isolevel<T>,
+    origin: [T; 3],
+    (mut a, mut b): (Vec3, Vec3),
+    (mut value_a, mut value_b): (T, T),
+    mut point: Vec3,
+) -> Vec3 {
+    let two = T::ONE + T::ONE;
+
+    // Which end was moved last, to halve the value of the other end when
+    // the same one is moved twice in a row.
+    let mut moved_a = None;
+
+    for _ in 0..8 {
+        let [x, y, z] = grid.to_world(cell_point(origin, point));
+        let value = scalar_field.sample(x, y, z);
+
+        if value == isolevel.level {
+            break;
+        }
+
+        if isolevel.is_inside(value) == isolevel.is_inside(value_a) {
+            (a, value_a) = (point, value);
+
+            if moved_a == Some(true) {
+                value_b = isolevel.level + (value_b - isolevel.level) / two;
+            }
+
+            moved_a = Some(true);
+        } else {
+            (b, value_b) = (point, value);
+
+            if moved_a == Some(false) {
+                value_a = isolevel.level + (value_a - isolevel.level) / two;
+            }
+
+            moved_a = Some(false);
+        }
+
+        point = a + (b - a) * isolevel.crossing(value_a, value_b).to_f32();
+    }
+
+    point
+}
+
+// Central differences of the field itself around a grid coordinate, in
+// world units. Differences over the samples would round off the very edges
+// the planes are meant to find.
+pub(crate) fn field_gradient<T: Real>(scalar_field: &(impl ScalarField<T> + ?Sized), grid: Grid<T>, coord: [T; 3]) -> Vec3 {
+    let step = grid.spacing.map(|spacing| spacing * T::from_f32(1e-3));
+    let [x, y, z] = grid.to_world(coord);
+    let two = T::ONE + T::ONE;
+
+    Vec3::from([
+        (scalar_field.sample(x + step[0], y, z) - scalar_field.sample(x - step[0], y, z)) / (two * step[0]),
+        (scalar_field.sample(x, y + step[1], z) - scalar_field.sample(x, y - step[1], z)) / (two * step[1]),
+        (scalar_field.sample(x, y, z + step[2]) - scalar_field.sample(x, y, z - step[2])) / (two * step[2]),
+    ].map(T::to_f32))
+}
+
+// Minimizes the squared distances to the planes through `points` with unit
+// `normals`. Where the planes don't pin down a single point, the one
+// closest to `mass_point` is used. At most `rank` directions are taken
+// from the planes, the ones they pin down best: 2 for a point along a
+// sharp edge, 3 for a corner.
+pub(crate) fn solve_qef(points: &[Vec3], normals: &[Vec3], mass_point: Vec3, rank: usize) -> Vec3 {
+    let mut ata = Mat3::ZERO;
+    let mut atb = Vec3::ZERO;
+
+    for (&point, &normal) in points.iter().zip(normals) {
+        ata += Mat3::from_cols(normal * normal.x, normal * normal.y, normal * normal.z);
+        atb += normal * normal.dot(point - mass_point);
+    }
+
+    let (eigenvalues, eigenvectors) = symmetric_eigen(ata);
+    let largest = eigenvalues.max_element();
+
+    let mut offset = Vec3::ZERO;
+
+    for i in 0..3 {
+        let better = (0..3)
+            .filter(|&j| eigenvalues[j] > eigenvalues[i] || (eigenvalues[j] == eigenvalues[i] && j < i))
+            .count();
+
+        if better < rank && eigenvalues[i] > largest * TRUNCATION {
+            let eigenvector = eigenvectors.col(i);
+            offset += eigenvector * eigenvector.dot(atb) / eigenvalues[i];
+        }
+    }
+
+    mass_point + offset
+}
+
+// Eigenvalues and eigenvectors (as columns) of a symmetric matrix, found
+// with Jacobi rotations.
+fn symmetric_eigen(matrix: Mat3) -> (Vec3, Mat3) {
+    let mut a = matrix.to_cols_array_2d();
+    let mut v = Mat3::IDENTITY.to_cols_array_2d();
+
+    for _ in 0..8 {
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q].abs() <= f32::EPSILON * (a[p][p].abs() + a[q][q].abs()) {
+                continue;
+            }
+
+            // Rotates the p-q plane so that a[p][q] becomes zero.
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let s = t * c;
+
+            for row in &mut a {
+                let (ap, aq) = (row[p], row[q]);
+                row[p] = c * ap - s * aq;
+                row[q] = s * ap + c * aq;
+            }
+
+            let (row_p, row_q) = (a[p], a[q]);
+            a[p] = std::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+            a[q] = std::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+
+            for row in &mut v {
+                let (vp, vq) = (row[p], row[q]);
+                row[p] = c * vp - s * vq;
+                row[q] = s * vp + c * vq;
+            }
+        }
+    }
+
+    // `v` was filled in as rows, so its transpose has the eigenvectors as
+    // columns.
+    (Vec3::new(a[0][0], a[1][1], a[2][2]), Mat3::from_cols_array_2d(&v).transpose())
+}
@@ -0,0 +1,174 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{Real, ScalarField};
+
+// A range of values, for bounding a field over a whole box at once instead
+// of sampling it. Results of the operations below contain every result of
+// the operation on values from the ranges, rounded outwards so that they
+// also hold the exact results the samples were rounded from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Interval<T: Real = f32> {
+    pub min: T,
+    pub max: T,
+}
+
+impl<T: Real> Interval<T> {
+    pub fn new(min: T, max: T) -> Self {
+        Self { min, max }
+    }
+
+    // The range holding only `value`.
+    pub fn point(value: T) -> Self {
+        Self::new(value, value)
+    }
+
+    // The range between two values, in either order.
+    pub fn between(a: T, b: T) -> Self {
+        Self::new(a.min(b), a.max(b))
+    }
+
+    // Bounds that were rounded to nearest, widened by a step to either side
+    // since the exact bound may lie past them.
+    fn rounded(min: T, max: T) -> Self {
+        Self::new(min.next_down(), max.next_up())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    // Tighter than `self * self`, which can't tell that both factors are
+    // the same value, and so goes negative for ranges holding zero.
+    pub fn square(self) -> Self {
+        if self.min >= T::ZERO {
+            Self::rounded(self.min * self.min, self.max * self.max)
+        } else if self.max <= T::ZERO {
+            Self::rounded(self.max * self.max, self.min * self.min)
+        } else {
+            Self::new(T::ZERO, (self.min * self.min).max(self.max * self.max).next_up())
+        }
+    }
+
+    // Negative values are left out, as they have no square root.
+    pub fn sqrt(self) -> Self {
+        Self::rounded(self.min.max(T::ZERO).sqrt(), self.max.max(T::ZERO).sqrt())
+    }
+
+    pub fn abs(self) -> Self {
+        if self.min >= T::ZERO {
+            self
+        } else if self.max <= T::ZERO {
+            -self
+        } else {
+            Self::new(T::ZERO, (-self.min).max(self.max))
+        }
+    }
+
+    pub fn min(self, other: Self) -> Self {
+        Self::new(self.min.min(other.min), self.max.min(other.max))
+    }
+
+    pub fn max(self, other: Self) -> Self {
+        Self::new(self.min.max(other.min), self.max.max(other.max))
+    }
+}
+
+impl<T: Real> Add for Interval<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::rounded(self.min + other.min, self.max + other.max)
+    }
+}
+
+impl<T: Real> Sub for Interval<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::rounded(self.min - other.max, self.max - other.min)
+    }
+}
+
+impl<T: Real> Mul for Interval<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let products = [self.min * other.min, self.min * other.max, self.max * other.min, self.max * other.max];
+
+        Self::rounded(
+            products.into_iter().fold(T::INFINITY, T::min),
+            products.into_iter().fold(T::NEG_INFINITY, T::max),
+        )
+    }
+}
+
+impl<T: Real> Neg for Interval<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.max, -self.min)
+    }
+}
+
+impl<T: Real> Add<T> for Interval<T> {
+    type Output = Self;
+
+    fn add(self, other: T) -> Self {
+        self + Self::point(other)
+    }
+}
+
+impl<T: Real> Sub<T> for Interval<T> {
+    type Output = Self;
+
+    fn sub(self, other: T) -> Self {
+        self - Self::point(other)
+    }
+}
+
+impl<T: Real> Mul<T> for Interval<T> {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self {
+        self * Self::point(other)
+    }
+}
+
+// Scalars on the left, which can't be implemented for every `Real` at
+// once.
+macro_rules! impl_scalar_ops {
+    ($($scalar:ty),*) => {$(
+        impl Add<Interval<$scalar>> for $scalar {
+            type Output = Interval<$scalar>;
+
+            fn add(self, other: Interval<$scalar>) -> Interval<$scalar> {
+                Interval::point(self) + other
+            }
+        }
+
+        impl Sub<Interval<$scalar>> for $scalar {
+            type Output = Interval<$scalar>;
+
+            fn sub(self, other: Interval<$scalar>) -> Interval<$scalar> {
+                Interval::point(self) - other
+            }
+        }
+
+        impl Mul<Interval<$scalar>> for $scalar {
+            type Output = Interval<$scalar>;
+
+            fn mul(self, other: Interval<$scalar>) -> Interval<$scalar> {
+                Interval::point(self) * other
+            }
+        }
+    )*};
+}
+
+impl_scalar_ops!(f32, f64);
+
+// A scalar field that can also bound its values over a box, such as an
+// analytic surface or a combination of SDF primitives. `interval` must
+// contain every value `sample` takes for points within the ranges.
+pub trait IntervalField<T: Real = f32>: ScalarField<T> {
+    fn interval(&self, x: Interval<T>, y: Interval<T>, z: Interval<T>) -> Interval<T>;
+}
@@ -0,0 +1,22 @@
+pub mod analysis;
+pub mod cross_section;
+pub mod decimation;
+pub mod dual_contouring;
+pub mod interval;
+mod marching_cubes;
+pub mod marching_squares;
+pub mod marching_tetrahedra;
+pub mod mesh_data;
+pub mod real;
+pub mod scalar_field;
+pub mod smoothing;
+pub mod sparse_voxel_grid;
+pub mod surface_nets;
+pub mod voxel_grid;
+
+pub use crate::marching_cubes::*;
+pub use mesh_data::MeshData;
+pub use real::Real;
+pub use scalar_field::{ScalarField, SyncScalarField};
+pub use sparse_voxel_grid::SparseVoxelGrid;
+pub use voxel_grid::{Grid, VoxelGrid, Voxels, VoxelsMut};
@@ -0,0 +1,575 @@
+mod capped;
+mod chunks;
+mod extended;
+mod incremental;
+pub mod march_tables;
+mod mc33;
+mod parallel;
+mod pruned;
+pub mod transition_tables;
+mod transvoxel;
+
+pub use capped::{marching_cubes_capped, marching_cubes_voxels_capped};
+pub use chunks::{marching_cubes_chunk, marching_cubes_chunks, marching_cubes_welded, Chunks};
+pub use extended::{extended_marching_cubes, Features};
+pub use incremental::{EditableVoxels, IncrementalMesh};
+pub use parallel::marching_cubes_parallel;
+pub use pruned::marching_cubes_pruned;
+pub use transvoxel::{transvoxel, Transitions};
+
+use march_tables::{POINTS, EDGES, TRIANGULATIONS};
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use glam::Vec3;
+
+use crate::real::{self, Real};
+use crate::voxel_grid::{ActiveBlocks, Voxels};
+use crate::{Grid, MeshData, ScalarField, VoxelGrid};
+
+// Which side of the isolevel counts as the inside of the surface.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Inside {
+    Below,
+    Above,
+}
+
+// The value at which the surface is extracted, together with which side
+// of it is considered solid. Normals always point away from the inside.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Isolevel<T: Real = f32> {
+    pub level: T,
+    pub inside: Inside,
+}
+
+impl<T: Real> Isolevel<T> {
+    pub fn new(level: T, inside: Inside) -> Self {
+        Self { level, inside }
+    }
+
+    pub fn is_inside(&self, value: T) -> bool {
+        match self.inside {
+            Inside::Below => value < self.level,
+            Inside::Above => value > self.level,
+        }
+    }
+
+    // Where between `value_a` and `value_b` the field crosses the level.
+    pub fn crossing(&self, value_a: T, value_b: T) -> T {
+        (self.level - value_a) / (value_b - value_a)
+    }
+
+    // A value a little way outside of the surface.
+    pub fn outside(&self) -> T {
+        match self.inside {
+            Inside::Below => self.level + T::ONE,
+            Inside::Above => self.level - T::ONE,
+        }
+    }
+
+    // Flips a field gradient so that it points away from the inside.
+    pub fn orient(&self, gradient: [T; 3]) -> [T; 3] {
+        match self.inside {
+            Inside::Below => gradient,
+            Inside::Above => gradient.map(|component| -component),
+        }
+    }
+}
+
+impl<T: Real> Default for Isolevel<T> {
+    fn default() -> Self {
+        Self::new(T::ZERO, Inside::Below)
+    }
+}
+
+// How cubes with ambiguous corner configurations are triangulated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Topology {
+    // The classic 256 case table. Ambiguous faces and cubes are always
+    // split the same way, whatever the samples, so the surface can join or
+    // separate regions where the field doesn't.
+    #[default]
+    Classic,
+    // Marching Cubes 33. Always watertight, and follows the topology of the
+    // trilinear interpolant of the samples, at some extra cost per cube.
+    Mc33,
+}
+
+// How the vertex normals of the indexed meshers are computed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Normals {
+    // The normals of the triangles around a vertex, weighted by their area.
+    #[default]
+    Area,
+    // The normals of the triangles around a vertex, weighted by their angle
+    // at the vertex. Long, thin triangles don't skew the result as much.
+    Angle,
+    // The gradient of the field, from central differences of the samples
+    // `epsilon` grid points apart, blended between the grid points around
+    // each vertex. Doesn't call the scalar field again.
+    Gradient { epsilon: usize },
+}
+
+pub fn marching_cubes<T: Real>(
+    grid: Grid<T>,
+    scalar_field: &(impl ScalarField<T> + ?Sized),
+    isolevel: Isolevel<T>,
+    topology: Topology,
+    normals: Normals,
+) -> MeshData<T> {
+    let voxel_grid = VoxelGrid::from_scalar_field(grid, scalar_field);
+
+    marching_cubes_voxels(&voxel_grid, isolevel, topology, normals)
+}
+
+// Same as `marching_cubes`, over samples that are already stored, such as
+// a `SparseVoxelGrid`. They are read in place rather than copied.
+pub fn marching_cubes_voxels<T: Real>(
+    voxels: &(impl Voxels<T> + ?Sized),
+    isolevel: Isolevel<T>,
+    topology: Topology,
+    normals: Normals,
+) -> MeshData<T> {
+    let active_blocks = voxels.min_max_pyramid().active_blocks(isolevel);
+
+    let Slab { positions, indices, .. } = march_slab(voxels, &active_blocks, isolevel, topology, 0..voxels.grid().size[2]);
+
+    let normals = calculate_normals(normals, &positions, &indices, voxels, isolevel);
+
+    MeshData::new(positions, normals, indices)
+}
+
+// The vertices and triangles of a range of layers. The edge indices of its
+// bottom and top planes are kept to stitch neighbouring slabs together.
+struct Slab<T: Real = f32> {
+    positions: Vec<[T; 3]>,
+    indices: Vec<u32>,
+    bottom: Vec<u32>,
+    top: Vec<u32>,
+}
+
+fn march_slab<T: Real, V: Voxels<T> + ?Sized>(
+    voxel_grid: &V,
+    active_blocks: &ActiveBlocks,
+    isolevel: Isolevel<T>,
+    topology: Topology,
+    layers: Range<usize>,
+) -> Slab<T> {
+    let grid = voxel_grid.grid();
+
+    let mut positions = Vec::<[T; 3]>::new();
+    let mut indices = Vec::<u32>::new();
+    let mut edge_cache = EdgeCache::new(voxel_grid.dimensions());
+    let mut bottom = Vec::new();
+
+    for z in layers.clone() {
+        edge_cache.advance(z);
+
+        for y in 0..grid.size[1] {
+            // Cubes in other blocks have all their corners on one side of
+            // the isolevel, and no triangles.
+            for x in active_blocks.row(y, z, grid.size[0]) {
+                triangulate_cube(
+                    (x, y, z),
+                    |x, y, z| voxel_grid.read(x, y, z),
+                    isolevel,
+                    topology,
+                    &mut positions,
+                    &mut indices,
+                    &mut edge_cache,
+                );
+            }
+        }
+
+        if z == layers.start {
+            bottom = edge_cache.plane(z).to_vec();
+        }
+    }
+
+    let top = edge_cache.plane(layers.end).to_vec();
+
+    for position in positions.iter_mut() {
+        *position = grid.to_world(*position);
+    }
+
+    Slab { positions, indices, bottom, top }
+}
+
+// Triangulates the cube at (x, y, z), reading the samples at its corners
+// with `read`. Its vertices are added to `positions` in grid coordinates,
+// and the ones on its edges are shared with other cubes through `edges`.
+pub(crate) fn triangulate_cube<T: Real>(
+    (x, y, z): (usize, usize, usize),
+    read: impl Fn(usize, usize, usize) -> T,
+    isolevel: Isolevel<T>,
+    topology: Topology,
+    positions: &mut Vec<[T; 3]>,
+    indices: &mut Vec<u32>,
+    edges: &mut impl EdgeVertices,
+) {
+    let values = POINTS.map(|(x0, y0, z0)| read(x + x0, y + y0, z + z0));
+
+    let (classic, resolved);
+
+    let (triangulation, centers): (&[i8], &[Vec3]) = match topology {
+        Topology::Classic => {
+            let config_idx = (0..8).fold(0, |config_idx, i| config_idx | (isolevel.is_inside(values[i]) as usize) << i);
+            classic = TRIANGULATIONS[config_idx];
+            (&classic, &[])
+        }
+        Topology::Mc33 => {
+            resolved = mc33::triangulate(values, isolevel);
+            (&resolved.triangles, &resolved.centers)
+        }
+    };
+
+    // Center vertices belong to this cube alone, so they are not shared.
+    let first_center = positions.len() as u32;
+
+    for &center in centers {
+        positions.push(real::add(Grid::point([x, y, z]), center.to_array().map(T::from_f32)));
+    }
+
+    for &edge_index in triangulation {
+        if edge_index.is_negative() { break; }
+
+        if edge_index >= mc33::CENTER {
+            indices.push(first_center + (edge_index - mc33::CENTER) as u32);
+            continue;
+        }
+
+        let (a, b) = EDGES[edge_index as usize];
+
+        let (x0, y0, z0) = POINTS[a];
+        let (x1, y1, z1) = POINTS[b];
+    
+        let edge_identifier = (x * 2 + x0 + x1, y * 2 + y0 + y1, z * 2 + z0 + z1);
+    
+        let cached_index = edges.get_mut(edge_identifier);
+
+        match *cached_index {
+            EdgeCache::EMPTY => {
+                let pos_a = Grid::point([x + x0, y + y0, z + z0]);
+                let pos_b = Grid::point([x + x1, y + y1, z + z1]);
+            
+                let t = isolevel.crossing(values[a], values[b]);
+            
+                indices.push(positions.len() as u32);
+                *cached_index = positions.len() as u32;
+                positions.push(real::add(pos_a, real::scale(real::sub(pos_b, pos_a), t)));
+            },
+            i => indices.push(i),
+        }
+    }
+}
+
+// Vertex indices of the edges of cubes, addressed by doubled edge-midpoint
+// coordinates, and `EdgeCache::EMPTY` for edges without a vertex yet.
+pub(crate) trait EdgeVertices {
+    fn get_mut(&mut self, edge: (usize, usize, usize)) -> &mut u32;
+}
+
+impl EdgeVertices for HashMap<(usize, usize, usize), u32> {
+    fn get_mut(&mut self, edge: (usize, usize, usize)) -> &mut u32 {
+        self.entry(edge).or_insert(EdgeCache::EMPTY)
+    }
+}
+
+// Vertex indices of the edges touching one layer of cubes, addressed by
+// doubled edge-midpoint coordinates. Edges lying in the planes below and
+// above the layer are kept in two alternating planes, so the top plane of
+// one layer is reused as the bottom plane of the next.
+struct EdgeCache {
+    dimensions: [usize; 3],
+    planes: [Vec<u32>; 2],
+    vertical: Vec<u32>,
+}
+
+impl EdgeCache {
+    const EMPTY: u32 = u32::MAX;
+
+    fn new(dimensions: [usize; 3]) -> Self {
+        let plane_len = dimensions[0] * dimensions[1];
+
+        Self {
+            dimensions,
+            planes: [vec![Self::EMPTY; plane_len * 2], vec![Self::EMPTY; plane_len * 2]],
+            vertical: vec![Self::EMPTY; plane_len],
+        }
+    }
+
+    // Clears the edges that layer `z` does not share with layer `z - 1`.
+    fn advance(&mut self, z: usize) {
+        self.planes[(z + 1) % 2].fill(Self::EMPTY);
+        self.vertical.fill(Self::EMPTY);
+    }
+
+    // The edges lying in the plane of grid points at height `z`.
+    fn plane(&self, z: usize) -> &[u32] {
+        &self.planes[z % 2]
+    }
+
+}
+
+impl EdgeVertices for EdgeCache {
+    fn get_mut(&mut self, (x, y, z): (usize, usize, usize)) -> &mut u32 {
+        let point = x / 2 + y / 2 * self.dimensions[0];
+
+        if z % 2 == 1 {
+            &mut self.vertical[point]
+        } else {
+            &mut self.planes[z / 2 % 2][point * 2 + y % 2]
+        }
+    }
+}
+
+pub fn marching_cubes_interpolation<T: Real>(
+    resolution: usize,
+    scalar_field: &(impl ScalarField<T> + ?Sized),
+    isolevel: Isolevel<T>,
+    interpolate: f32,
+    normal_weight: f32,
+) -> MeshData<T> {
+    let voxel_grid = VoxelGrid::from_scalar_field(Grid::cube(resolution), scalar_field);
+
+    let mut positions = Vec::<[T; 3]>::new();
+
+    for z in 0..resolution {
+        for y in 0..resolution {
+            for x in 0..resolution {
+                
+                let triangulation = get_triangulation((x, y, z), &voxel_grid, isolevel);
+
+                for edge_index in triangulation {
+                    if edge_index == -1 { break; }
+
+                    make_vertex_interpolation((x, y, z), &voxel_grid, isolevel, &mut positions, edge_index as usize, interpolate);
+                }
+            }
+        }
+    }
+
+    let gradient_normals = calculate_gradient_normals(&positions, scalar_field, isolevel);
+    let flat_normals = calculate_flat_normals(&positions);
+
+    let mut normals = vec![[0.0; 3]; positions.len()];
+
+    for i in 0..positions.len() {
+        let f_n = flat_normals[i];
+        let g_n = gradient_normals[i];
+
+        normals[i] = [f_n[0] + (g_n[0] - f_n[0]) * normal_weight, f_n[1] + (g_n[1] - f_n[1]) * normal_weight, f_n[2] + (g_n[2] - f_n[2]) * normal_weight];
+    }
+
+    MeshData::unindexed(positions, normals)
+}
+
+pub fn marching_cubes_disjointed<T: Real>(
+    resolution: usize,
+    scalar_field: &(impl ScalarField<T> + ?Sized),
+    isolevel: Isolevel<T>,
+) -> Vec<MeshData<T>> {
+    let voxel_grid = VoxelGrid::from_scalar_field(Grid::cube(resolution), scalar_field);
+
+    let mut meshes = Vec::new();
+
+    for z in 0..resolution {
+        for y in 0..resolution {
+            for x in 0..resolution {
+                let mut positions = Vec::<[T; 3]>::new();
+                
+                let triangulation = get_triangulation((x, y, z), &voxel_grid, isolevel);
+
+                for edge_index in triangulation {
+                    if edge_index == -1 { break; }
+
+                    make_vertex_interpolation((x, y, z), &voxel_grid, isolevel, &mut positions, edge_index as usize, 0.0);
+                }
+
+                let normals = calculate_flat_normals(&positions);
+
+                meshes.push(MeshData::unindexed(positions, normals));
+            }
+        }
+    }
+
+    meshes
+}
+
+fn make_vertex_interpolation<T: Real>(
+    (x, y, z): (usize, usize, usize),
+    voxel_grid: &VoxelGrid<T>,
+    isolevel: Isolevel<T>,
+    positions: &mut Vec<[T; 3]>,
+    edge_index: usize,
+    interpolate: f32,
+) {
+    let point_index = EDGES[edge_index];
+
+    let (x0, y0, z0) = POINTS[point_index.0];
+    let (x1, y1, z1) = POINTS[point_index.1];
+
+    let val_a = voxel_grid.read(x + x0, y + y0, z + z0);
+    let val_b = voxel_grid.read(x + x1, y + y1, z + z1);
+
+    let pos_a = Grid::point([x + x0, y + y0, z + z0]);
+    let pos_b = Grid::point([x + x1, y + y1, z + z1]);
+
+    let t = isolevel.crossing(val_a, val_b);
+
+    let half = T::from_f32(0.5);
+    let t2 = half + (t - half) * T::from_f32(interpolate);
+
+    let position = real::add(pos_a, real::scale(real::sub(pos_b, pos_a), t2));
+
+    positions.push(position);
+}
+
+// The samples at the corners of a cube, in the order of `POINTS`.
+fn read_cube<T: Real, V: Voxels<T> + ?Sized>(
+    (x, y, z): (usize, usize, usize),
+    voxel_grid: &V,
+) -> [T; 8] {
+    POINTS.map(|(x0, y0, z0)| voxel_grid.read(x + x0, y + y0, z + z0))
+}
+
+fn get_triangulation<T: Real, V: Voxels<T> + ?Sized>(
+    (x, y, z): (usize, usize, usize),
+    voxel_grid: &V,
+    isolevel: Isolevel<T>,
+) -> [i8; 15] {
+    let mut config_idx = 0b00000000;
+
+    config_idx |=  isolevel.is_inside(voxel_grid.read(  x  ,  y  ,  z  )) as u8;
+    config_idx |= (isolevel.is_inside(voxel_grid.read(  x  ,  y  ,z + 1)) as u8) << 1;
+    config_idx |= (isolevel.is_inside(voxel_grid.read(x + 1,  y  ,z + 1)) as u8) << 2;
+    config_idx |= (isolevel.is_inside(voxel_grid.read(x + 1,  y  ,  z  )) as u8) << 3;
+    config_idx |= (isolevel.is_inside(voxel_grid.read(  x  ,y + 1,  z  )) as u8) << 4;
+    config_idx |= (isolevel.is_inside(voxel_grid.read(  x  ,y + 1,z + 1)) as u8) << 5;
+    config_idx |= (isolevel.is_inside(voxel_grid.read(x + 1,y + 1,z + 1)) as u8) << 6;
+    config_idx |= (isolevel.is_inside(voxel_grid.read(x + 1,y + 1,  z  )) as u8) << 7;
+    
+    TRIANGULATIONS[config_idx as usize]
+}
+
+pub(crate) fn calculate_normals<T: Real, V: Voxels<T> + ?Sized>(
+    normals: Normals,
+    positions: &[[T; 3]],
+    indices: &[u32],
+    voxel_grid: &V,
+    isolevel: Isolevel<T>,
+) -> Vec<[f32; 3]> {
+    match normals {
+        Normals::Area => calculate_smooth_normals(positions, indices),
+        Normals::Angle => calculate_angle_normals(positions, indices),
+        Normals::Gradient { epsilon } => calculate_grid_gradient_normals(positions, voxel_grid, isolevel, epsilon),
+    }
+}
+
+pub(crate) fn calculate_smooth_normals<T: Real>(
+    positions: &[[T; 3]],
+    indices: &[u32],
+) -> Vec<[f32; 3]> {
+    let mut normals = vec![[0.0; 3]; positions.len()];
+
+    for triangle in indices.chunks_exact(3) {
+        let [i1, i2, i3] = [0, 1, 2].map(|k| triangle[k] as usize);
+
+        let (p1, p2, p3) = (positions[i1], positions[i2], positions[i3]);
+
+        let n = real::offset(p3, p1).cross(real::offset(p2, p1));
+
+        let n1: Vec3 = normals[i1].into();
+        let n2: Vec3 = normals[i2].into();
+        let n3: Vec3 = normals[i3].into();
+
+        normals[i1] = (n1 + n).into();
+        normals[i2] = (n2 + n).into();
+        normals[i3] = (n3 + n).into();
+    }
+
+    for normal in normals.iter_mut() {
+        *normal = Vec3::from(*normal).normalize().into();
+    }
+
+    normals
+}
+
+fn calculate_angle_normals<T: Real>(
+    positions: &[[T; 3]],
+    indices: &[u32],
+) -> Vec<[f32; 3]> {
+    let mut normals = vec![Vec3::ZERO; positions.len()];
+
+    for triangle in indices.chunks_exact(3) {
+        let points = [0, 1, 2].map(|k| positions[triangle[k] as usize]);
+
+        let n = real::offset(points[2], points[0]).cross(real::offset(points[1], points[0])).normalize_or_zero();
+
+        for k in 0..3 {
+            let angle = real::offset(points[(k + 1) % 3], points[k]).angle_between(real::offset(points[(k + 2) % 3], points[k]));
+
+            // Degenerate triangles have no angle to speak of.
+            if angle.is_finite() {
+                normals[triangle[k] as usize] += n * angle;
+            }
+        }
+    }
+
+    normals.into_iter().map(|normal| normal.normalize_or_zero().into()).collect()
+}
+
+fn calculate_grid_gradient_normals<T: Real, V: Voxels<T> + ?Sized>(
+    positions: &[[T; 3]],
+    voxel_grid: &V,
+    isolevel: Isolevel<T>,
+    epsilon: usize,
+) -> Vec<[f32; 3]> {
+    positions
+        .iter()
+        .map(|&position| {
+            let gradient = isolevel.orient(voxel_grid.interpolated_gradient(position, epsilon));
+            Vec3::from(gradient.map(T::to_f32)).normalize_or_zero().into()
+        })
+        .collect()
+}
+
+fn gradient<T: Real>(x: T, y: T, z: T, scalar_field: &(impl ScalarField<T> + ?Sized)) -> [T; 3] {
+    let e = T::ONE;
+    let val_x = scalar_field.sample(x + e, y, z) - scalar_field.sample(x - e, y, z);
+    let val_y = scalar_field.sample(x, y + e, z) - scalar_field.sample(x, y - e, z);
+    let val_z = scalar_field.sample(x, y, z + e) - scalar_field.sample(x, y, z - e);
+    [val_x, val_y, val_z]
+}
+
+fn calculate_gradient_normals<T: Real>(
+    positions: &[[T; 3]],
+    scalar_field: &(impl ScalarField<T> + ?Sized),
+    isolevel: Isolevel<T>,
+) -> Vec<[f32; 3]> {
+    let mut normals = vec![[0.0; 3]; positions.len()];
+
+    for (i, position) in positions.iter().enumerate() {
+        let normal = Vec3::from(isolevel.orient(gradient(position[0], position[1], position[2], scalar_field)).map(T::to_f32)).normalize();
+        normals[i] = normal.into();
+    }
+
+    normals
+}
+
+fn calculate_flat_normals<T: Real>(
+    positions: &[[T; 3]],
+) -> Vec<[f32; 3]> {
+    let mut normals = vec![[0.0; 3]; positions.len()];
+
+    for (triangle, normals) in positions.chunks_exact(3).zip(normals.chunks_exact_mut(3)) {
+        let [p1, p2, p3] = [triangle[0], triangle[1], triangle[2]];
+
+        let n = real::offset(p3, p1).cross(real::offset(p2, p1));
+
+        normals.fill(n.normalize().into());
+    }
+
+    normals
+}
\ No newline at end of file
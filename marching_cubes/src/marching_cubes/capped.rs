@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+
+use super::*;
+
+use crate::voxel_grid::Padded;
+
+// Same as `marching_cubes`, but counts everything beyond the grid as
+// outside, so that the surface is capped where it leaves the grid and
+// every piece of it is closed.
+pub fn marching_cubes_capped<T: Real>(
+    grid: Grid<T>,
+    scalar_field: &(impl ScalarField<T> + ?Sized),
+    isolevel: Isolevel<T>,
+    topology: Topology,
+    normals: Normals,
+) -> MeshData<T> {
+    let voxel_grid = VoxelGrid::from_scalar_field(grid, scalar_field);
+
+    marching_cubes_voxels_capped(&voxel_grid, isolevel, topology, normals)
+}
+
+// Same as `marching_cubes_capped`, over samples that are already stored.
+//
+// The samples are padded with a layer of outside points, and the cubes
+// reaching into that layer through a face of the grid are marched as
+// well. Their vertices on edges into the padding are moved onto the grid
+// point at the inner end, so that the caps lie flat on the faces of the
+// grid. Cubes reaching into it along an edge or corner of the grid would
+// only add triangles without area there, and are left out.
+pub fn marching_cubes_voxels_capped<T: Real>(
+    voxels: &(impl Voxels<T> + ?Sized),
+    isolevel: Isolevel<T>,
+    topology: Topology,
+    normals: Normals,
+) -> MeshData<T> {
+    let padded = Padded::new(voxels, isolevel);
+    let size = padded.grid().size;
+    let active_blocks = padded.min_max_pyramid().active_blocks(isolevel);
+
+    let mut marcher = CappedMarcher {
+        grid: voxels.grid(),
+        isolevel,
+        topology,
+        positions: Vec::new(),
+        indices: Vec::new(),
+        vertices: HashMap::new(),
+    };
+
+    // The cubes of the grid go first, in the same order as in
+    // `marching_cubes`, so that they make the same vertices and triangles
+    // and the caps only add to them.
+    for pass in 0..2 {
+        for z in 0..size[2] {
+            for y in 0..size[1] {
+                for x in active_blocks.row(y, z, size[0]) {
+                    let in_padding = [x, y, z]
+                        .iter()
+                        .zip(size)
+                        .filter(|&(&c, size)| c == 0 || c == size - 1)
+                        .count();
+
+                    if in_padding == pass {
+                        marcher.march_cube(&padded, [x, y, z]);
+                    }
+                }
+            }
+        }
+    }
+
+    let CappedMarcher { positions, indices, .. } = marcher;
+
+    let normals = calculate_normals(normals, &positions, &indices, &padded, isolevel);
+
+    MeshData::new(positions, normals, indices)
+}
+
+// Marches the cubes of padded voxels, with vertices placed in the grid of
+// the voxels without padding.
+struct CappedMarcher<T: Real> {
+    grid: Grid<T>,
+    isolevel: Isolevel<T>,
+    topology: Topology,
+    positions: Vec<[T; 3]>,
+    indices: Vec<u32>,
+    // Vertex indices by doubled edge-midpoint coordinates in the padded
+    // grid. Vertices moved onto a grid point are kept under the (all even)
+    // coordinates of that point instead.
+    vertices: HashMap<[usize; 3], u32>,
+}
+
+impl<T: Real> CappedMarcher<T> {
+    fn march_cube<V: Voxels<T> + ?Sized>(&mut self, padded: &Padded<V, T>, [x, y, z]: [usize; 3]) {
+        let (classic, resolved);
+
+        let (triangulation, centers): (&[i8], &[Vec3]) = match self.topology {
+            Topology::Classic => {
+                classic = get_triangulation((x, y, z), padded, self.isolevel);
+                (&classic, &[])
+            }
+            Topology::Mc33 => {
+                resolved = mc33::triangulate(read_cube((x, y, z), padded), self.isolevel);
+                (&resolved.triangles, &resolved.centers)
+            }
+        };
+
+        // Center vertices belong to this cube alone, so they are not kept.
+        // Ones in the padding are pressed flat onto the grid.
+        let first_center = self.positions.len() as u32;
+        let size = Grid::point(self.grid.size);
+
+        for &center in centers {
+            let position = real::add(real::sub(Grid::point([x, y, z]), [T::ONE; 3]), center.to_array().map(T::from_f32));
+            let position = [0, 1, 2].map(|axis| position[axis].max(T::ZERO).min(size[axis]));
+            self.positions.push(self.grid.to_world(position));
+        }
+
+        for &edge_index in triangulation {
+            if edge_index.is_negative() { break; }
+
+            if edge_index >= mc33::CENTER {
+                self.indices.push(first_center + (edge_index - mc33::CENTER) as u32);
+                continue;
+            }
+
+            let (a, b) = EDGES[edge_index as usize];
+            let (x0, y0, z0) = POINTS[a];
+            let (x1, y1, z1) = POINTS[b];
+
+            let (point_a, point_b) = ([x + x0, y + y0, z + z0], [x + x1, y + y1, z + z1]);
+
+            let snapped = match (padded.is_padding(point_a), padded.is_padding(point_b)) {
+                (true, _) => Some(point_b),
+                (_, true) => Some(point_a),
+                _ => None,
+            };
+
+            let key = match snapped {
+                Some(point) => point.map(|c| c * 2),
+                None => [x * 2 + x0 + x1, y * 2 + y0 + y1, z * 2 + z0 + z1],
+            };
+
+            let index = match self.vertices.get(&key) {
+                Some(&index) => index,
+                None => {
+                    let position = match snapped {
+                        Some(point) => Grid::point(point.map(|c| c - 1)),
+                        None => {
+                            let pos_a = Grid::point([x + x0 - 1, y + y0 - 1, z + z0 - 1]);
+                            let pos_b = Grid::point([x + x1 - 1, y + y1 - 1, z + z1 - 1]);
+
+                            let val_a = padded.read(x + x0, y + y0, z + z0);
+                            let val_b = padded.read(x + x1, y + y1, z + z1);
+
+                            let t = self.isolevel.crossing(val_a, val_b);
+
+                            real::add(pos_a, real::scale(real::sub(pos_b, pos_a), t))
+                        }
+                    };
+
+                    let index = self.positions.len() as u32;
+                    self.positions.push(self.grid.to_world(position));
+                    self.vertices.insert(key, index);
+                    index
+                }
+            };
+
+            self.indices.push(index);
+        }
+    }
+}
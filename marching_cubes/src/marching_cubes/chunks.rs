@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+
+use super::*;
+
+// A volume split into `count` chunks along each axis, laid out next to
+// `chunk`, the grid of the first one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Chunks<T: Real = f32> {
+    pub chunk: Grid<T>,
+    pub count: [usize; 3],
+}
+
+impl<T: Real> Chunks<T> {
+    pub fn new(chunk: Grid<T>, count: [usize; 3]) -> Self {
+        Self { chunk, count }
+    }
+
+    // The grid of the whole volume.
+    pub fn volume(&self) -> Grid<T> {
+        let size = [0, 1, 2].map(|i| self.chunk.size[i] * self.count[i]);
+
+        Grid { size, ..self.chunk }
+    }
+
+    // The grid of the chunk at `coord`, counted in chunks.
+    pub fn grid(&self, coord: [usize; 3]) -> Grid<T> {
+        let start = self.start(coord);
+
+        self.chunk.with_origin(self.volume().to_world(Grid::point(start)))
+    }
+
+    // Every chunk coordinate, x-major.
+    pub fn coords(&self) -> impl Iterator<Item = [usize; 3]> {
+        let [count_x, count_y, count_z] = self.count;
+
+        (0..count_z).flat_map(move |z| (0..count_y).flat_map(move |y| (0..count_x).map(move |x| [x, y, z])))
+    }
+
+    // The first grid point of the chunk at `coord` in the volume.
+    fn start(&self, coord: [usize; 3]) -> [usize; 3] {
+        [0, 1, 2].map(|i| coord[i] * self.chunk.size[i])
+    }
+}
+
+// Meshes a single chunk of `chunks` on its own, so that chunks can be
+// spread over threads or meshed as they come into view. The samples are
+// padded by a cell on each side that has a neighbouring chunk (more for
+// gradient normals), so the normals along the border take the triangles of
+// the neighbours into account. Vertices shared with a neighbour get the
+// same position and normal in both chunks, and the chunks together make
+// the same triangles as `marching_cubes` over the whole volume.
+pub fn marching_cubes_chunk<T: Real>(
+    chunks: Chunks<T>,
+    coord: [usize; 3],
+    scalar_field: &(impl ScalarField<T> + ?Sized),
+    isolevel: Isolevel<T>,
+    topology: Topology,
+    normals: Normals,
+) -> MeshData<T> {
+    mesh_chunk(chunks, coord, scalar_field, isolevel, topology, normals).0
+}
+
+// All chunks of `chunks`, meshed on their own by `marching_cubes_chunk`,
+// in the order of `Chunks::coords`.
+pub fn marching_cubes_chunks<T: Real>(
+    chunks: Chunks<T>,
+    scalar_field: &(impl ScalarField<T> + ?Sized),
+    isolevel: Isolevel<T>,
+    topology: Topology,
+    normals: Normals,
+) -> Vec<MeshData<T>> {
+    chunks.coords()
+        .map(|coord| marching_cubes_chunk(chunks, coord, scalar_field, isolevel, topology, normals))
+        .collect()
+}
+
+// Meshes the chunks one by one like `marching_cubes_chunks`, and welds
+// them into a single mesh in which vertices along the borders between
+// chunks are shared.
+pub fn marching_cubes_welded<T: Real>(
+    chunks: Chunks<T>,
+    scalar_field: &(impl ScalarField<T> + ?Sized),
+    isolevel: Isolevel<T>,
+    topology: Topology,
+    normals: Normals,
+) -> MeshData<T> {
+    let mut welded = MeshData::default();
+    let mut vertices: HashMap<[usize; 3], u32> = HashMap::new();
+
+    for coord in chunks.coords() {
+        let (mesh, keys) = mesh_chunk(chunks, coord, scalar_field, isolevel, topology, normals);
+
+        let remap: Vec<u32> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| {
+                let mut add = || {
+                    welded.positions.push(mesh.positions[i]);
+                    welded.normals.extend(mesh.normals.get(i));
+                    welded.positions.len() as u32 - 1
+                };
+
+                match key {
+                    Some(key) => *vertices.entry(*key).or_insert_with(add),
+                    None => add(),
+                }
+            })
+            .collect();
+
+        welded.indices.extend(mesh.indices.iter().map(|&i| remap[i as usize]));
+    }
+
+    welded
+}
+
+// The mesh of one chunk, along with the doubled edge-midpoint coordinates
+// in the volume of each of its vertices. Vertices in the middle of a cube
+// have none.
+fn mesh_chunk<T: Real>(
+    chunks: Chunks<T>,
+    coord: [usize; 3],
+    scalar_field: &(impl ScalarField<T> + ?Sized),
+    isolevel: Isolevel<T>,
+    topology: Topology,
+    normals: Normals,
+) -> (MeshData<T>, Vec<Option<[usize; 3]>>) {
+    let volume = chunks.volume();
+    let start = chunks.start(coord);
+    let end = [0, 1, 2].map(|i| start[i] + chunks.chunk.size[i]);
+
+    let padding = match normals {
+        Normals::Gradient { epsilon } => 1 + epsilon,
+        _ => 1,
+    };
+
+    // The volume ends where there are no neighbours, so the padding stops
+    // there as well.
+    let low = start.map(|s| s.saturating_sub(padding));
+    let high = [0, 1, 2].map(|i| (end[i] + padding).min(volume.size[i]));
+
+    let padded = Grid::new([0, 1, 2].map(|i| high[i] - low[i]))
+        .with_origin(volume.to_world(Grid::point(low)))
+        .with_spacing(volume.spacing);
+
+    // Sampled at the same world positions as the whole volume, so the
+    // samples of neighbouring chunks agree.
+    let voxel_grid = VoxelGrid::from_scalar_field_at(padded, scalar_field, volume, low);
+
+    let active_blocks = voxel_grid.min_max_pyramid().active_blocks(isolevel);
+
+    // Vertices are placed from their grid coordinates in the whole volume,
+    // so that they come out the same in every chunk that shares them.
+    let mut positions = Vec::new();
+    let mut indices = Vec::new();
+    let mut vertices = HashMap::new();
+    let mut own = Vec::new();
+
+    for z in 0..padded.size[2] {
+        for y in 0..padded.size[1] {
+            for x in active_blocks.row(y, z, padded.size[0]) {
+                let first = indices.len();
+
+                triangulate_cube(
+                    (low[0] + x, low[1] + y, low[2] + z),
+                    |x, y, z| voxel_grid.read(x - low[0], y - low[1], z - low[2]),
+                    isolevel,
+                    topology,
+                    &mut positions,
+                    &mut indices,
+                    &mut vertices,
+                );
+
+                let inside = (0..3).all(|i| (start[i]..end[i]).contains(&(low[i] + [x, y, z][i])));
+                own.extend(std::iter::repeat_n(inside, (indices.len() - first) / 3));
+            }
+        }
+    }
+
+    for position in positions.iter_mut() {
+        *position = volume.to_world(*position);
+    }
+
+    // Center vertices belong to a single cube, and have no key.
+    let mut keys = vec![None; positions.len()];
+
+    for ((x, y, z), index) in vertices {
+        keys[index as usize] = Some([x, y, z]);
+    }
+
+    // Normals over the padding as well, before it is dropped.
+    let normals = calculate_normals(normals, &positions, &indices, &voxel_grid, isolevel);
+
+    let mut remap = vec![u32::MAX; positions.len()];
+    let mut kept = Vec::new();
+    let mut own_indices = Vec::new();
+
+    for (triangle, _) in indices.chunks_exact(3).zip(&own).filter(|(_, &own)| own) {
+        for &index in triangle {
+            if remap[index as usize] == u32::MAX {
+                remap[index as usize] = kept.len() as u32;
+                kept.push(index as usize);
+            }
+
+            own_indices.push(remap[index as usize]);
+        }
+    }
+
+    let positions = kept.iter().map(|&i| positions[i]).collect();
+    let normals = kept.iter().map(|&i| normals[i]).collect();
+    let keys = kept.iter().map(|&i| keys[i]).collect();
+
+    (MeshData::new(positions, normals, own_indices), keys)
+}
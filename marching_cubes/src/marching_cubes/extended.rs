@@ -0,0 +1,312 @@
+use std::collections::{HashMap, HashSet};
+
+use glam::Vec3;
+
+use super::march_tables::{EDGES, POINTS, TRIANGULATIONS};
+use super::Isolevel;
+use crate::dual_contouring::{cell_point, field_gradient, orient_normal, refine_crossing, solve_qef};
+use crate::{Grid, MeshData, Real, ScalarField, VoxelGrid, Voxels};
+
+// When the normals of a patch of surface in one cube count as a sharp
+// feature, and when such a feature is a corner rather than an edge.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Features {
+    // Smallest angle, in radians, between two normals of a patch for it to
+    // hold a feature.
+    pub sharp_angle: f32,
+    // Smallest angle, in radians, by which a normal of a feature leaves the
+    // plane of the two normals furthest apart for it to be a corner.
+    pub corner_angle: f32,
+}
+
+impl Features {
+    pub fn new(sharp_angle: f32, corner_angle: f32) -> Self {
+        Self { sharp_angle, corner_angle }
+    }
+}
+
+impl Default for Features {
+    fn default() -> Self {
+        Self::new(std::f32::consts::FRAC_PI_6, std::f32::consts::FRAC_PI_4)
+    }
+}
+
+// Extended Marching Cubes (Kobbelt et al., 2001). Every cube is
+// triangulated from `TRIANGULATIONS`, with its vertices moved onto the
+// field and given the normal of the field there. Patches of a cube whose
+// normals spread further than `features` allows are replaced by a fan
+// around a vertex placed on the sharp edge or corner they cut off, and
+// edges between the fans of neighbouring cubes are then flipped to run
+// along the feature. Meshes of smooth fields have the triangles of
+// `marching_cubes` with `Topology::Classic`, only with moved vertices.
+//
+// Vertices on features have the average normal of their patch.
+pub fn extended_marching_cubes<T: Real>(
+    grid: Grid<T>,
+    scalar_field: &(impl ScalarField<T> + ?Sized),
+    isolevel: Isolevel<T>,
+    features: Features,
+) -> MeshData<T> {
+    let voxel_grid = VoxelGrid::from_scalar_field(grid, scalar_field);
+    let active_blocks = voxel_grid.min_max_pyramid().active_blocks(isolevel);
+
+    let mut mesher = Mesher {
+        grid,
+        scalar_field,
+        isolevel,
+        features,
+        positions: Vec::new(),
+        normals: Vec::new(),
+        indices: Vec::new(),
+        hermite: Vec::new(),
+        vertices: HashMap::new(),
+        feature_cubes: HashMap::new(),
+    };
+
+    for z in 0..grid.size[2] {
+        for y in 0..grid.size[1] {
+            for x in active_blocks.row(y, z, grid.size[0]) {
+                let values = POINTS.map(|(x0, y0, z0)| voxel_grid.read(x + x0, y + y0, z + z0));
+                mesher.march_cube([x, y, z], values);
+            }
+        }
+    }
+
+    mesher.flip_edges();
+
+    let Mesher { positions, normals, indices, .. } = mesher;
+
+    MeshData::new(positions, normals, indices)
+}
+
+struct Mesher<'a, T: Real, F: ScalarField<T> + ?Sized> {
+    grid: Grid<T>,
+    scalar_field: &'a F,
+    isolevel: Isolevel<T>,
+    features: Features,
+    positions: Vec<[T; 3]>,
+    normals: Vec<[f32; 3]>,
+    indices: Vec<u32>,
+    // Grid coordinate and field gradient of every vertex.
+    hermite: Vec<(Vec3, Vec3)>,
+    // Vertex indices addressed by doubled edge-midpoint coordinates.
+    vertices: HashMap<[usize; 3], u32>,
+    // The cube of every vertex placed on a feature.
+    feature_cubes: HashMap<u32, [usize; 3]>,
+}
+
+// A vertex where the surface crosses an edge of a cube.
+struct Crossing {
+    index: u32,
+    // Position within the cube, in grid units.
+    point: Vec3,
+    gradient: Vec3,
+}
+
+impl<T: Real, F: ScalarField<T> + ?Sized> Mesher<'_, T, F> {
+    fn march_cube(&mut self, cube: [usize; 3], values: [T; 8]) {
+        let config_idx = (0..8).fold(0, |config_idx, i| config_idx | (self.isolevel.is_inside(values[i]) as usize) << i);
+
+        let triangles: Vec<[usize; 3]> = TRIANGULATIONS[config_idx]
+            .chunks_exact(3)
+            .take_while(|triangle| !triangle[0].is_negative())
+            .map(|triangle| [0, 1, 2].map(|k| triangle[k] as usize))
+            .collect();
+
+        let crossings: HashMap<usize, Crossing> = triangles
+            .iter()
+            .flatten()
+            .map(|&edge_index| (edge_index, self.crossing(cube, values, edge_index)))
+            .collect();
+
+        for patch in patches(&triangles) {
+            let feature = self.feature(&patch, &crossings);
+
+            match feature {
+                None => {
+                    for triangle in patch {
+                        self.indices.extend(triangle.map(|edge_index| crossings[&edge_index].index));
+                    }
+                }
+                Some(rank) => self.fan(cube, &patch, &crossings, rank),
+            }
+        }
+    }
+
+    // The vertex on an edge of a cube, made by the first cube to use it.
+    fn crossing(&mut self, [x, y, z]: [usize; 3], values: [T; 8], edge_index: usize) -> Crossing {
+        let (a, b) = EDGES[edge_index];
+        let (x0, y0, z0) = POINTS[a];
+        let (x1, y1, z1) = POINTS[b];
+
+        let origin = Vec3::new(x as f32, y as f32, z as f32);
+        let ends = (Vec3::new(x0 as f32, y0 as f32, z0 as f32), Vec3::new(x1 as f32, y1 as f32, z1 as f32));
+
+        let key = [x * 2 + x0 + x1, y * 2 + y0 + y1, z * 2 + z0 + z1];
+
+        let index = match self.vertices.get(&key) {
+            Some(&index) => index,
+            None => {
+                let t = self.isolevel.crossing(values[a], values[b]).to_f32();
+                let point = ends.0 + (ends.1 - ends.0) * t;
+                let point = refine_crossing(self.scalar_field, self.grid, self.isolevel, Grid::point([x, y, z]), ends, (values[a], values[b]), point);
+                let gradient = field_gradient(self.scalar_field, self.grid, cell_point(Grid::point([x, y, z]), point));
+
+                let index = self.positions.len() as u32;
+                self.positions.push(self.grid.to_world(cell_point(Grid::point([x, y, z]), point)));
+                self.normals.push(orient_normal(self.isolevel, gradient));
+                self.hermite.push((origin + point, gradient));
+                self.vertices.insert(key, index);
+                index
+            }
+        };
+
+        let (coord, gradient) = self.hermite[index as usize];
+
+        Crossing { index, point: coord - origin, gradient }
+    }
+
+    // None for a smooth patch, otherwise the number of directions the
+    // feature vertex is pinned down in: 2 along an edge, 3 at a corner.
+    fn feature(&self, patch: &[[usize; 3]], crossings: &HashMap<usize, Crossing>) -> Option<usize> {
+        let normals: Vec<Vec3> = patch_edges(patch).map(|edge_index| crossings[&edge_index].gradient.normalize_or_zero()).collect();
+
+        // The two normals furthest apart span the normal cone.
+        let (mut widest, mut pair) = (1.0, (0, 0));
+
+        for i in 0..normals.len() {
+            for j in i + 1..normals.len() {
+                let cos = normals[i].dot(normals[j]);
+
+                if cos < widest {
+                    (widest, pair) = (cos, (i, j));
+                }
+            }
+        }
+
+        if widest >= self.features.sharp_angle.cos() {
+            return None;
+        }
+
+        // Along an edge, every normal is close to the plane of those two.
+        let direction = normals[pair.0].cross(normals[pair.1]).normalize_or_zero();
+        let out_of_plane = normals.iter().map(|normal| normal.dot(direction).abs()).fold(0.0, f32::max);
+
+        Some(if out_of_plane > self.features.corner_angle.sin() { 3 } else { 2 })
+    }
+
+    // Replaces a patch by a fan around a vertex on its feature.
+    fn fan(&mut self, cube: [usize; 3], patch: &[[usize; 3]], crossings: &HashMap<usize, Crossing>, rank: usize) {
+        let patch_crossings: Vec<&Crossing> = patch_edges(patch).map(|edge_index| &crossings[&edge_index]).collect();
+
+        let points: Vec<Vec3> = patch_crossings.iter().map(|crossing| crossing.point).collect();
+        let mass_point = points.iter().fold(Vec3::ZERO, |sum, &point| sum + point) / points.len() as f32;
+
+        // The planes are solved for in grid units, in which they are tilted
+        // by the spacing.
+        let spacing = Vec3::from(self.grid.spacing.map(T::to_f32));
+        let planes: Vec<Vec3> = patch_crossings.iter().map(|crossing| (crossing.gradient * spacing).normalize_or_zero()).collect();
+
+        let point = solve_qef(&points, &planes, mass_point, rank).clamp(Vec3::ZERO, Vec3::ONE);
+
+        let normal = patch_crossings.iter().fold(Vec3::ZERO, |sum, crossing| sum + crossing.gradient.normalize_or_zero());
+        let origin = Vec3::new(cube[0] as f32, cube[1] as f32, cube[2] as f32);
+
+        let center = self.positions.len() as u32;
+        self.positions.push(self.grid.to_world(cell_point(Grid::point(cube), point)));
+        self.normals.push(orient_normal(self.isolevel, normal));
+        self.hermite.push((origin + point, normal));
+        self.feature_cubes.insert(center, cube);
+
+        // Edges used by a single triangle of the patch run around its
+        // border, in the winding of the patch.
+        let edges: Vec<(usize, usize)> = patch.iter().flat_map(|&[a, b, c]| [(a, b), (b, c), (c, a)]).collect();
+
+        for &(a, b) in &edges {
+            if !edges.contains(&(b, a)) {
+                self.indices.extend([center, crossings[&a].index, crossings[&b].index]);
+            }
+        }
+    }
+
+    // Flips the edges between two vertices of the regular surface whose
+    // triangles both end in feature vertices of neighbouring cubes, so
+    // that the mesh runs along the feature between them.
+    fn flip_edges(&mut self) {
+        let triangle_count = self.indices.len() / 3;
+        let mut triangles_of_edge: HashMap<(u32, u32), usize> = HashMap::new();
+
+        for triangle in 0..triangle_count {
+            for k in 0..3 {
+                let (a, b) = (self.indices[triangle * 3 + k], self.indices[triangle * 3 + (k + 1) % 3]);
+                triangles_of_edge.insert((a, b), triangle);
+            }
+        }
+
+        let mut edges: HashSet<(u32, u32)> = triangles_of_edge.keys().map(|&(a, b)| (a.min(b), a.max(b))).collect();
+        let mut flipped = vec![false; triangle_count];
+
+        let mut candidates: Vec<(u32, u32)> = edges.iter().copied().collect();
+        candidates.sort_unstable();
+
+        for (u, v) in candidates {
+            if self.feature_cubes.contains_key(&u) || self.feature_cubes.contains_key(&v) {
+                continue;
+            }
+
+            let (Some(&first), Some(&second)) = (triangles_of_edge.get(&(u, v)), triangles_of_edge.get(&(v, u))) else { continue };
+
+            if flipped[first] || flipped[second] {
+                continue;
+            }
+
+            let opposite = |triangle: usize| (0..3).map(|k| self.indices[triangle * 3 + k]).find(|&i| i != u && i != v).unwrap();
+            let (f1, f2) = (opposite(first), opposite(second));
+
+            let (Some(c1), Some(c2)) = (self.feature_cubes.get(&f1), self.feature_cubes.get(&f2)) else { continue };
+
+            let neighbours = (0..3).map(|axis| c1[axis].abs_diff(c2[axis])).sum::<usize>() == 1;
+
+            if !neighbours || edges.contains(&(f1.min(f2), f1.max(f2))) {
+                continue;
+            }
+
+            // The triangles (u, v, f1) and (v, u, f2) make up the quad
+            // u, f2, v, f1, which is split along f1-f2 instead.
+            self.indices[first * 3..first * 3 + 3].copy_from_slice(&[u, f2, f1]);
+            self.indices[second * 3..second * 3 + 3].copy_from_slice(&[f2, v, f1]);
+
+            edges.remove(&(u, v));
+            edges.insert((f1.min(f2), f1.max(f2)));
+            flipped[first] = true;
+            flipped[second] = true;
+        }
+    }
+}
+
+// Splits the triangles of a cube into the patches of surface they form,
+// which share no vertices with each other.
+fn patches(triangles: &[[usize; 3]]) -> Vec<Vec<[usize; 3]>> {
+    let mut patches: Vec<Vec<[usize; 3]>> = Vec::new();
+
+    for &triangle in triangles {
+        let (touching, separate): (Vec<_>, Vec<_>) = patches
+            .into_iter()
+            .partition(|patch| patch.iter().flatten().any(|edge_index| triangle.contains(edge_index)));
+
+        let mut merged: Vec<[usize; 3]> = touching.into_iter().flatten().collect();
+        merged.push(triangle);
+
+        patches = separate;
+        patches.push(merged);
+    }
+
+    patches
+}
+
+// The edges of a cube a patch has vertices on, each once.
+fn patch_edges(patch: &[[usize; 3]]) -> impl Iterator<Item = usize> + '_ {
+    let mut seen = [false; 12];
+
+    patch.iter().flatten().copied().filter(move |&edge_index| !std::mem::replace(&mut seen[edge_index], true))
+}
@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+
+use glam::Vec3;
+
+use super::march_tables::{EDGES, POINTS};
+use super::{calculate_normals, get_triangulation, mc33, read_cube, Isolevel, Normals, Topology};
+use crate::real::{self, Real};
+use crate::voxel_grid::{Voxels, VoxelsMut};
+use crate::{Grid, MeshData};
+
+// Voxels that remember which boxes of grid points were written since they
+// were last meshed by an `IncrementalMesh`.
+pub struct EditableVoxels<V> {
+    voxels: V,
+    // Inclusive (low, high) corners of boxes of grid points.
+    dirty: Vec<([usize; 3], [usize; 3])>,
+}
+
+impl<V> EditableVoxels<V> {
+    pub fn new(voxels: V) -> Self {
+        Self { voxels, dirty: Vec::new() }
+    }
+
+    // Sets one sample. Points next to an existing dirty box grow it rather
+    // than starting a new one, so brush strokes end up as a few boxes.
+    pub fn write<T: Real>(&mut self, x: usize, y: usize, z: usize, value: T)
+    where
+        V: VoxelsMut<T>,
+    {
+        self.voxels.write(x, y, z, value);
+
+        let point = [x, y, z];
+        let touching = self.dirty.iter_mut().find(|(low, high)| {
+            (0..3).all(|axis| low[axis] <= point[axis] + 1 && point[axis] <= high[axis] + 1)
+        });
+
+        match touching {
+            Some((low, high)) => {
+                *low = std::array::from_fn(|axis| low[axis].min(point[axis]));
+                *high = std::array::from_fn(|axis| high[axis].max(point[axis]));
+            }
+            None => self.dirty.push((point, point)),
+        }
+    }
+
+    // Boxes of grid points written since the last update.
+    pub fn dirty(&self) -> &[([usize; 3], [usize; 3])] {
+        &self.dirty
+    }
+
+    pub fn voxels(&self) -> &V {
+        &self.voxels
+    }
+
+    pub fn into_inner(self) -> V {
+        self.voxels
+    }
+}
+
+impl<T: Real, V: Voxels<T>> Voxels<T> for EditableVoxels<V> {
+    fn grid(&self) -> Grid<T> {
+        self.voxels.grid()
+    }
+
+    fn read(&self, x: usize, y: usize, z: usize) -> T {
+        self.voxels.read(x, y, z)
+    }
+
+    fn dimensions(&self) -> [usize; 3] {
+        self.voxels.dimensions()
+    }
+}
+
+// A `marching_cubes` mesh that is patched in place as its voxels change.
+// Only the cubes around written grid points are marched again. Vertices
+// on edges whose ends didn't change keep their index and position, which
+// also keeps the surface closed against the cubes that were left alone.
+//
+// Vertices that are no longer used stay in the mesh, unreferenced, until
+// a later update reuses their slot. Triangles are not kept in any
+// particular order.
+pub struct IncrementalMesh<T: Real = f32> {
+    isolevel: Isolevel<T>,
+    topology: Topology,
+    normals: Normals,
+    mesh: MeshData<T>,
+    // Vertex indices addressed by doubled edge-midpoint coordinates.
+    vertices: HashMap<[usize; 3], u32>,
+    // Edge of each vertex, or None for MC33 centers and unused slots.
+    keys: Vec<Option<[usize; 3]>>,
+    // Number of triangle corners using each vertex.
+    uses: Vec<u32>,
+    free: Vec<u32>,
+    // Triangle numbers of every cube with triangles, and the cube of every
+    // triangle.
+    cubes: HashMap<[usize; 3], Vec<usize>>,
+    owners: Vec<[usize; 3]>,
+}
+
+impl<T: Real> IncrementalMesh<T> {
+    // Marches every cube of `voxels`, like `marching_cubes_voxels`.
+    pub fn new(
+        voxels: &(impl Voxels<T> + ?Sized),
+        isolevel: Isolevel<T>,
+        topology: Topology,
+        normals: Normals,
+    ) -> Self {
+        let mut mesh = Self {
+            isolevel,
+            topology,
+            normals,
+            mesh: MeshData::default(),
+            vertices: HashMap::new(),
+            keys: Vec::new(),
+            uses: Vec::new(),
+            free: Vec::new(),
+            cubes: HashMap::new(),
+            owners: Vec::new(),
+        };
+
+        let size = voxels.grid().size;
+        let active_blocks = voxels.min_max_pyramid().active_blocks(isolevel);
+
+        for z in 0..size[2] {
+            for y in 0..size[1] {
+                for x in active_blocks.row(y, z, size[0]) {
+                    mesh.march_cube(voxels, [x, y, z]);
+                }
+            }
+        }
+
+        let MeshData { positions, indices, .. } = &mesh.mesh;
+        mesh.mesh.normals = calculate_normals(normals, positions, indices, voxels, isolevel);
+
+        mesh
+    }
+
+    pub fn mesh(&self) -> &MeshData<T> {
+        &self.mesh
+    }
+
+    // Marches the cubes around the grid points written since the last
+    // update again, and forgets about them.
+    pub fn update<V: VoxelsMut<T>>(&mut self, voxels: &mut EditableVoxels<V>) {
+        for (low, high) in std::mem::take(&mut voxels.dirty) {
+            self.remesh(&voxels.voxels, low, high);
+        }
+    }
+
+    // Marches the cubes touching the grid points from `low` to `high`,
+    // inclusive, again.
+    pub fn remesh(&mut self, voxels: &(impl Voxels<T> + ?Sized), low: [usize; 3], high: [usize; 3]) {
+        let size = voxels.grid().size;
+
+        if size.contains(&0) {
+            return;
+        }
+
+        let cubes = (low.map(|c| c.saturating_sub(1)), std::array::from_fn(|axis| high[axis].min(size[axis] - 1)));
+
+        let mut touched = Vec::new();
+
+        for cube in cubes_in(cubes) {
+            self.clear_cube(cube, &mut touched);
+        }
+
+        for cube in cubes_in(cubes) {
+            self.march_cube(voxels, cube);
+        }
+
+        // Vertices only used by the old triangles. The ones still in use
+        // were found again by the new triangles, and kept their index.
+        touched.sort_unstable();
+        touched.dedup();
+
+        for index in touched {
+            if self.uses[index as usize] == 0 {
+                if let Some(key) = self.keys[index as usize].take() {
+                    self.vertices.remove(&key);
+                }
+
+                self.free.push(index);
+            }
+        }
+
+        self.update_normals(voxels, cubes);
+    }
+
+    // Removes the triangles of a cube, collecting the vertices they used.
+    fn clear_cube(&mut self, cube: [usize; 3], touched: &mut Vec<u32>) {
+        let Some(mut triangles) = self.cubes.remove(&cube) else { return };
+
+        // From the back, so the triangles moved into the gaps are never
+        // ones of this cube that are still to be removed.
+        triangles.sort_unstable_by(|a, b| b.cmp(a));
+
+        for triangle in triangles {
+            for k in 0..3 {
+                let index = self.mesh.indices[triangle * 3 + k];
+                self.uses[index as usize] -= 1;
+                touched.push(index);
+            }
+
+            let last = self.owners.len() - 1;
+
+            if triangle != last {
+                let moved = self.owners[last];
+                self.cubes.get_mut(&moved).unwrap().iter_mut().filter(|t| **t == last).for_each(|t| *t = triangle);
+
+                self.mesh.indices.copy_within(last * 3..last * 3 + 3, triangle * 3);
+                self.owners[triangle] = moved;
+            }
+
+            self.owners.pop();
+            self.mesh.indices.truncate(last * 3);
+        }
+    }
+
+    fn march_cube(&mut self, voxels: &(impl Voxels<T> + ?Sized), [x, y, z]: [usize; 3]) {
+        let (classic, resolved);
+
+        let (triangulation, centers): (&[i8], &[Vec3]) = match self.topology {
+            Topology::Classic => {
+                classic = get_triangulation((x, y, z), voxels, self.isolevel);
+                (&classic, &[])
+            }
+            Topology::Mc33 => {
+                resolved = mc33::triangulate(read_cube((x, y, z), voxels), self.isolevel);
+                (&resolved.triangles, &resolved.centers)
+            }
+        };
+
+        if triangulation.first().is_none_or(|edge_index| edge_index.is_negative()) {
+            return;
+        }
+
+        let grid = voxels.grid();
+
+        let centers: Vec<u32> = centers
+            .iter()
+            .map(|&center| {
+                let position = real::add(Grid::point([x, y, z]), center.to_array().map(T::from_f32));
+                self.add_vertex(None, grid.to_world(position))
+            })
+            .collect();
+
+        let mut corners = Vec::new();
+
+        for &edge_index in triangulation {
+            if edge_index.is_negative() { break; }
+
+            if edge_index >= mc33::CENTER {
+                corners.push(centers[(edge_index - mc33::CENTER) as usize]);
+                continue;
+            }
+
+            let (a, b) = EDGES[edge_index as usize];
+            let (x0, y0, z0) = POINTS[a];
+            let (x1, y1, z1) = POINTS[b];
+
+            let key = [x * 2 + x0 + x1, y * 2 + y0 + y1, z * 2 + z0 + z1];
+
+            let index = match self.vertices.get(&key).copied() {
+                Some(index) if self.uses[index as usize] > 0 => index,
+                // Unused vertices were left by the cubes being marched
+                // again, and their edge may have moved.
+                found => {
+                    let pos_a = Grid::point([x + x0, y + y0, z + z0]);
+                    let pos_b = Grid::point([x + x1, y + y1, z + z1]);
+
+                    let val_a = voxels.read(x + x0, y + y0, z + z0);
+                    let val_b = voxels.read(x + x1, y + y1, z + z1);
+
+                    let t = self.isolevel.crossing(val_a, val_b);
+                    let position = grid.to_world(real::add(pos_a, real::scale(real::sub(pos_b, pos_a), t)));
+
+                    match found {
+                        Some(index) => {
+                            self.mesh.positions[index as usize] = position;
+                            index
+                        }
+                        None => self.add_vertex(Some(key), position),
+                    }
+                }
+            };
+
+            corners.push(index);
+        }
+
+        let triangles = self.cubes.entry([x, y, z]).or_default();
+
+        for triangle in corners.chunks_exact(3) {
+            triangles.push(self.owners.len());
+            self.owners.push([x, y, z]);
+
+            for &index in triangle {
+                self.uses[index as usize] += 1;
+                self.mesh.indices.push(index);
+            }
+        }
+    }
+
+    fn add_vertex(&mut self, key: Option<[usize; 3]>, position: [T; 3]) -> u32 {
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.mesh.positions[index as usize] = position;
+                index
+            }
+            None => {
+                self.mesh.positions.push(position);
+                self.mesh.normals.push([0.0; 3]);
+                self.keys.push(None);
+                self.uses.push(0);
+                self.mesh.positions.len() as u32 - 1
+            }
+        };
+
+        if let Some(key) = key {
+            self.vertices.insert(key, index);
+        }
+
+        self.keys[index as usize] = key;
+        index
+    }
+
+    // Normals of the vertices whose triangles, or whose samples nearby,
+    // changed with the cubes in `cubes`.
+    fn update_normals(&mut self, voxels: &(impl Voxels<T> + ?Sized), cubes: ([usize; 3], [usize; 3])) {
+        let size = voxels.grid().size;
+        let grow = |(low, high): ([usize; 3], [usize; 3]), by: usize| -> ([usize; 3], [usize; 3]) {
+            (low.map(|c| c.saturating_sub(by)), std::array::from_fn(|axis| (high[axis] + by).min(size[axis] - 1)))
+        };
+
+        let triangles_in = |cubes| {
+            cubes_in(cubes)
+                .filter_map(|cube| self.cubes.get(&cube))
+                .flatten()
+                .map(|&triangle| [0, 1, 2].map(|k| self.mesh.indices[triangle * 3 + k]))
+                .collect::<Vec<_>>()
+        };
+
+        match self.normals {
+            Normals::Area | Normals::Angle => {
+                // Every triangle around a vertex of a cube is in a cube
+                // next to it.
+                let mut sums: HashMap<u32, Vec3> = triangles_in(grow(cubes, 1))
+                    .into_iter()
+                    .flatten()
+                    .map(|index| (index, Vec3::ZERO))
+                    .collect();
+
+                for triangle in triangles_in(grow(cubes, 2)) {
+                    let points = triangle.map(|index| self.mesh.positions[index as usize]);
+                    let n = real::offset(points[2], points[0]).cross(real::offset(points[1], points[0]));
+
+                    for k in 0..3 {
+                        let Some(sum) = sums.get_mut(&triangle[k]) else { continue };
+
+                        if matches!(self.normals, Normals::Area) {
+                            *sum += n;
+                        } else {
+                            let angle = real::offset(points[(k + 1) % 3], points[k]).angle_between(real::offset(points[(k + 2) % 3], points[k]));
+
+                            if angle.is_finite() {
+                                *sum += n.normalize_or_zero() * angle;
+                            }
+                        }
+                    }
+                }
+
+                for (index, sum) in sums {
+                    self.mesh.normals[index as usize] = sum.normalize_or_zero().into();
+                }
+            }
+            Normals::Gradient { epsilon } => {
+                // Gradients read samples up to `epsilon` grid points away.
+                for index in triangles_in(grow(cubes, epsilon.max(1))).into_iter().flatten() {
+                    let gradient = self.isolevel.orient(voxels.interpolated_gradient(self.mesh.positions[index as usize], epsilon));
+                    self.mesh.normals[index as usize] = Vec3::from(gradient.map(T::to_f32)).normalize_or_zero().into();
+                }
+            }
+        }
+    }
+}
+
+// The cubes in an inclusive box.
+fn cubes_in((low, high): ([usize; 3], [usize; 3])) -> impl Iterator<Item = [usize; 3]> {
+    (low[2]..=high[2]).flat_map(move |z| {
+        (low[1]..=high[1]).flat_map(move |y| (low[0]..=high[0]).map(move |x| [x, y, z]))
+    })
+}
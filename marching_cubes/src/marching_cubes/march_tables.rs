@@ -290,3 +290,14 @@ pub const POINTS: [(usize, usize, usize); 8] = [
     (1, 1, 0),
 ];
 
+// Indices to the points array for the corners of each face, counter-
+// clockwise when seen from outside the cube, followed by indices to the
+// edges array between consecutive corners
+pub const FACES: [([usize; 4], [usize; 4]); 6] = [
+    ([0, 3, 2, 1], [3, 2, 1, 0]),
+    ([4, 5, 6, 7], [4, 5, 6, 7]),
+    ([0, 1, 5, 4], [0, 9, 4, 8]),
+    ([3, 7, 6, 2], [11, 6, 10, 2]),
+    ([0, 4, 7, 3], [8, 7, 11, 3]),
+    ([1, 2, 6, 5], [1, 10, 5, 9]),
+];
@@ -0,0 +1,515 @@
+// Topologically correct triangulation of single cubes, as in Chernyaev's
+// Marching Cubes 33. Instead of listing its cases and subcases in tables,
+// the surface is built the same way they are derived:
+//
+// - Faces with inside corners on only one diagonal are split with the
+//   asymptotic decider. It only looks at the corners of the face, so both
+//   cubes sharing a face always split it the same way.
+// - The segments this gives on the faces are chained into closed loops
+//   around the cube, and each loop is closed with a fan of triangles.
+// - When a saddle point of the trilinear interpolant inside the cube
+//   connects two regions that are separate on its faces, the two loops
+//   around them are joined by a tunnel instead.
+//
+// No triangle edge may run over a face of the cube unless it is one of the
+// segments there, as the neighbouring cube might use the same edge. Where
+// that can't be helped, the fan or parts of the tunnel are made around
+// extra vertices in the cube, like some of the subcases of Marching Cubes
+// 33 are.
+//
+// Cubes without any of these ambiguities use the classic table.
+
+use glam::{DVec3, Vec3};
+
+use super::march_tables::{EDGES, FACES, POINTS, TRIANGULATIONS};
+use super::{Inside, Isolevel};
+use crate::Real;
+
+// Stands in for an edge index when a triangle uses the first center vertex,
+// and `CENTER + 1` for the second.
+pub(super) const CENTER: i8 = 12;
+
+pub(super) struct Triangulation {
+    // Edge indices (or centers) of at most sixteen triangles, terminated by
+    // -1 when shorter.
+    pub triangles: [i8; 48],
+    // Positions of the center vertices relative to the first corner of the
+    // cube. There are at most two.
+    pub centers: Vec<Vec3>,
+}
+
+pub(super) fn triangulate<T: Real>(values: [T; 8], isolevel: Isolevel<T>) -> Triangulation {
+    // Distance of each corner from the level, negative on the inside. Kept
+    // in the precision of the samples, as distances too small for single
+    // precision still decide which corners are inside and how faces are
+    // split. Only the positions of centers are single precision.
+    let values = values.map(|value| match isolevel.inside {
+        Inside::Below => value - isolevel.level,
+        Inside::Above => isolevel.level - value,
+    });
+
+    let mut patches = Patches::new(&values);
+    let mut ambiguous = false;
+
+    // For every edge the surface crosses, the next one along the loop around
+    // the cube it belongs to.
+    let mut next = [-1; 12];
+
+    for (corners, edges) in FACES {
+        let inside = corners.map(|corner| values[corner] < T::ZERO);
+
+        let joined = if inside[0] == inside[2] && inside[1] == inside[3] && inside[0] != inside[1] {
+            ambiguous = true;
+
+            let (a, b) = if inside[0] { (0, 1) } else { (1, 0) };
+            let joined = joins_inside(values[corners[a]] * values[corners[a + 2]], values[corners[b]] * values[corners[b + 2]]);
+
+            if joined {
+                patches.join(corners[a], corners[a + 2]);
+            } else {
+                patches.join(corners[b], corners[b + 2]);
+            }
+
+            joined
+        } else {
+            false
+        };
+
+        // Walking around the face, a segment cuts off the inside corners
+        // between an edge leading into them and the next edge leading out.
+        // Joined inside corners are instead cut off from the outside corner
+        // between them. Loops run backwards over the segments, which gives
+        // the winding of the classic table.
+        let leaves = |k: usize| inside[k % 4] && !inside[(k + 1) % 4];
+
+        for k in 0..4 {
+            if !inside[k] && inside[(k + 1) % 4] {
+                let exit = match joined {
+                    false => (1..4).map(|i| k + i).find(|&i| leaves(i)),
+                    true => (1..4).map(|i| k + 4 - i).find(|&i| leaves(i)),
+                };
+
+                next[edges[exit.unwrap() % 4]] = edges[k] as i8;
+            }
+        }
+    }
+
+    let tunnel = find_tunnel(&values, &mut patches);
+
+    let mut triangles = [-1; 48];
+    let mut centers = Vec::new();
+
+    if !ambiguous && tunnel.is_none() {
+        triangles[..15].copy_from_slice(&TRIANGULATIONS[config_index(&values)]);
+        return Triangulation { triangles, centers };
+    }
+
+    let loops = find_loops(&next, &values, &mut patches);
+
+    // The two loops around the regions the tunnel connects. With exactly
+    // two such regions, they are both next to one region of the other kind.
+    let tunnel = tunnel.and_then(|inside| {
+        let sides = |l: &Loop| if inside { (l.inside, l.outside) } else { (l.outside, l.inside) };
+
+        (0..loops.len())
+            .flat_map(|a| (a + 1..loops.len()).map(move |b| (a, b)))
+            .find(|&(a, b)| {
+                let (ends_a, between_a) = sides(&loops[a]);
+                let (ends_b, between_b) = sides(&loops[b]);
+                ends_a != ends_b && between_a == between_b
+            })
+    });
+
+    let mut count = 0;
+    let mut push = |triangle: [i8; 3]| {
+        triangles[count..count + 3].copy_from_slice(&triangle);
+        count += 3;
+    };
+
+    for (i, l) in loops.iter().enumerate() {
+        let edges = &l.edges;
+        let n = edges.len();
+
+        match tunnel {
+            Some((a, b)) if i == a => make_tunnel(edges, &loops[b].edges, &values, &next, &mut centers, &mut push),
+            Some((_, b)) if i == b => (),
+            _ => match fan_apex(edges, &next) {
+                Some(apex) => {
+                    for j in 1..n - 1 {
+                        push([edges[apex], edges[(apex + j) % n], edges[(apex + j + 1) % n]]);
+                    }
+                }
+                None => {
+                    let center = CENTER + centers.len() as i8;
+                    centers.push(average(&values, edges));
+
+                    for j in 0..n {
+                        push([center, edges[j], edges[(j + 1) % n]]);
+                    }
+                }
+            },
+        }
+    }
+
+    Triangulation { triangles, centers }
+}
+
+fn config_index<T: Real>(values: &[T; 8]) -> usize {
+    values.iter().enumerate().fold(0, |config, (i, &value)| config | ((value < T::ZERO) as usize) << i)
+}
+
+// Asymptotic decider: the two inside corners of an ambiguous face are
+// connected when the saddle of the bilinear interpolant over the face is
+// inside. Given the products of the inside and outside diagonals, that is
+// the case exactly when the inside product is larger. Both products are
+// positive and computed the same way by both cubes sharing the face.
+fn joins_inside<T: Real>(inside_product: T, outside_product: T) -> bool {
+    inside_product > outside_product
+}
+
+// Corners connected to each other on the faces of the cube, kept as a
+// union-find forest.
+struct Patches {
+    parents: [usize; 8],
+    inside: [bool; 8],
+}
+
+impl Patches {
+    fn new<T: Real>(values: &[T; 8]) -> Self {
+        let mut patches = Self {
+            parents: [0, 1, 2, 3, 4, 5, 6, 7],
+            inside: values.map(|value| value < T::ZERO),
+        };
+
+        for (a, b) in EDGES {
+            if patches.inside[a] == patches.inside[b] {
+                patches.join(a, b);
+            }
+        }
+
+        patches
+    }
+
+    fn find(&mut self, corner: usize) -> usize {
+        let mut root = corner;
+
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        self.parents[corner] = root;
+        root
+    }
+
+    fn join(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        self.parents[a] = b;
+    }
+
+    // Number of separate patches with the given side.
+    fn count(&mut self, inside: bool) -> usize {
+        (0..8).filter(|&corner| self.inside[corner] == inside && self.find(corner) == corner).count()
+    }
+}
+
+// A closed loop of edge crossings around the cube, oriented the same way
+// as the triangles of the classic table. `inside` and `outside` are the
+// patches on either side of it.
+struct Loop {
+    edges: Vec<i8>,
+    inside: usize,
+    outside: usize,
+}
+
+fn find_loops<T: Real>(next: &[i8; 12], values: &[T; 8], patches: &mut Patches) -> Vec<Loop> {
+    let mut loops = Vec::new();
+    let mut visited = [false; 12];
+
+    for start in 0..12 {
+        if next[start] < 0 || visited[start] {
+            continue;
+        }
+
+        let mut edges = Vec::new();
+        let mut edge = start;
+
+        while !visited[edge] {
+            visited[edge] = true;
+            edges.push(edge as i8);
+            edge = next[edge] as usize;
+        }
+
+        let (a, b) = EDGES[start];
+        let (inside, outside) = if values[a] < T::ZERO { (a, b) } else { (b, a) };
+
+        loops.push(Loop {
+            edges,
+            inside: patches.find(inside),
+            outside: patches.find(outside),
+        });
+    }
+
+    loops
+}
+
+// Whether the interior of the cube connects two inside (`Some(true)`) or
+// two outside (`Some(false)`) patches that are separate on its faces.
+//
+// The trilinear interpolant has no extrema inside the cube, so regions can
+// only be joined through one of its (at most two) body saddle points. One
+// of them can only join inside regions, and does so when it lies in the
+// cube and is inside itself. The other likewise joins outside regions.
+// The first is always the higher one, so at most one of them applies, and
+// it only ever applies when there are exactly two patches to join.
+fn find_tunnel<T: Real>(values: &[T; 8], patches: &mut Patches) -> Option<bool> {
+    if patches.count(true) == 2 && body_saddle(values, true).is_some_and(|value| value < 0.0) {
+        Some(true)
+    } else if patches.count(false) == 2 && body_saddle(values, false).is_some_and(|value| value >= 0.0) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+// The value at the body saddle point joining inside or outside regions,
+// if it lies in the cube.
+//
+// When the cubic term is small, the saddles lie near the cube while the
+// point they are found around is far away, so this is worked out in double
+// precision and the value is read off the interpolant at the saddle itself.
+fn body_saddle<T: Real>(values: &[T; 8], inside: bool) -> Option<f64> {
+    let [v000, v001, v101, v100, v010, v011, v111, v110] = values.map(T::to_f64);
+
+    // F(x, y, z) = a + bx + cy + dz + exy + fyz + gxz + hxyz
+    let a = v000;
+    let b = v100 - v000;
+    let c = v010 - v000;
+    let d = v001 - v000;
+    let e = v110 - v100 - v010 + v000;
+    let f = v011 - v010 - v001 + v000;
+    let g = v101 - v100 - v001 + v000;
+    let h = v111 - v110 - v101 - v011 + v100 + v010 + v001 - v000;
+
+    // Without the cubic term, there is a single critical point. Its Hessian
+    // has a trace of zero, so it is a saddle, and it joins inside regions
+    // when only one of its eigenvalues is negative.
+    let point = if h == 0.0 {
+        let det = 2.0 * e * f * g;
+
+        if det == 0.0 || (det < 0.0) != inside {
+            return None;
+        }
+
+        DVec3::new(
+            e * f * d + f * g * c - f * f * b,
+            e * g * d + f * g * b - g * g * c,
+            e * f * b + e * g * c - e * e * d,
+        ) / -det
+    } else {
+        // Around (x0, y0, z0), F = hXYZ + pX + qY + rZ + s, with X = x - x0
+        // and so on. Its critical points have YZ = -p/h, XZ = -q/h and
+        // XY = -r/h.
+        let (x0, y0, z0) = (-f / h, -g / h, -e / h);
+
+        let p = b + e * y0 + g * z0 + h * y0 * z0;
+        let q = c + e * x0 + f * z0 + h * x0 * z0;
+        let r = d + f * y0 + g * x0 + h * x0 * y0;
+
+        let xyz_squared = -p * q * r / (h * h * h);
+
+        if xyz_squared.is_nan() || xyz_squared <= 0.0 {
+            return None;
+        }
+
+        // The saddle joining inside regions is the one where hXYZ is
+        // negative.
+        let xyz = match inside {
+            true => -h.signum() * xyz_squared.sqrt(),
+            false => h.signum() * xyz_squared.sqrt(),
+        };
+
+        DVec3::new(x0 - h * xyz / p, y0 - h * xyz / q, z0 - h * xyz / r)
+    };
+
+    if point.cmplt(DVec3::ZERO).any() || point.cmpgt(DVec3::ONE).any() {
+        return None;
+    }
+
+    let DVec3 { x, y, z } = point;
+
+    Some(a + b * x + c * y + d * z + e * x * y + f * y * z + g * x * z + h * x * y * z)
+}
+
+// Whether the edge between two crossings runs over a face of the cube
+// without being one of the segments there.
+fn cuts_face(next: &[i8; 12], a: i8, b: i8) -> bool {
+    face_mask(a) & face_mask(b) != 0 && next[a as usize] != b && next[b as usize] != a
+}
+
+// The faces of the cube an edge lies on, as a bitmask over `FACES`.
+fn face_mask(edge: i8) -> u8 {
+    FACES.iter().enumerate()
+        .filter(|(_, (_, edges))| edges.contains(&(edge as usize)))
+        .fold(0, |mask, (face, _)| mask | 1 << face)
+}
+
+// A crossing from which the loop can be fanned without cutting any face.
+fn fan_apex(edges: &[i8], next: &[i8; 12]) -> Option<usize> {
+    (0..edges.len()).find(|&apex| edges.iter().all(|&edge| !cuts_face(next, edges[apex], edge)))
+}
+
+#[derive(Clone, Copy)]
+enum Step {
+    From,
+    To,
+    // Around a center, starting from the edge across to `a(i)` and `b(j)`.
+    Center(usize, usize),
+}
+
+// Joins two loops with a band of triangles, each made of one edge of a loop
+// and one crossing of the other. Seen along the tunnel, the loops run in
+// opposite directions, so `to` is walked backwards. Parts of the band may
+// instead be fanned around up to two centers. Out of all such bands, the
+// one with the shortest edges across the tunnel is used, with centers only
+// where needed to keep from cutting faces.
+fn make_tunnel<T: Real>(
+    from: &[i8],
+    to: &[i8],
+    values: &[T; 8],
+    next: &[i8; 12],
+    centers: &mut Vec<Vec3>,
+    push: &mut impl FnMut([i8; 3]),
+) {
+    // Dwarf the length of any band, so centers are only used if necessary,
+    // and edges cutting faces only if nothing else works.
+    const CENTER_COST: f32 = 100.0;
+    const CUT_COST: f32 = 10000.0;
+
+    let (n, m) = (from.len(), to.len());
+
+    let mut best: Option<(f32, Vec<[i8; 3]>, Vec<Vec3>)> = None;
+
+    for (from_start, to_start) in (0..n).flat_map(|i| (0..m).map(move |j| (i, j))) {
+        let a = |i: usize| from[(from_start + i) % n];
+        let b = |j: usize| to[(to_start + m - j % m) % m];
+
+        // Starting along `from` and ending along `to`, without going around
+        // all of `from` first, keeps the band from reaching across the same
+        // two crossings twice. Trying every start, this loses nothing.
+        let allowed = |i: usize, j: usize| !(i == 0 && j > 0 || i < n && j == m || i == n && j == 0);
+
+        let across = |i: usize, j: usize| {
+            let cut = if cuts_face(next, a(i), b(j)) { CUT_COST } else { 0.0 };
+            crossing(values, a(i)).distance_squared(crossing(values, b(j))) + cut
+        };
+
+        // The cheapest band with `c` centers up to reaching across from
+        // `a(i)` to `b(j)`, and the last step it took there.
+        let mut cost = vec![vec![vec![(f32::INFINITY, Step::From); m + 1]; n + 1]; 3];
+        cost[0][0][0].0 = across(0, 0);
+
+        for i in 0..=n {
+            for j in 0..=m {
+                if !allowed(i, j) {
+                    continue;
+                }
+
+                for c in 0..3 {
+                    let mut steps = Vec::new();
+
+                    if i > 0 {
+                        steps.push((cost[c][i - 1][j].0, Step::From));
+                    }
+
+                    if j > 0 {
+                        steps.push((cost[c][i][j - 1].0, Step::To));
+                    }
+
+                    // A center can't go all the way around either loop, or
+                    // it would reach the same crossing twice.
+                    if c > 0 {
+                        for (i0, j0) in (i.saturating_sub(n - 1)..=i).flat_map(|i0| (j.saturating_sub(m - 1)..=j).map(move |j0| (i0, j0))) {
+                            if (i0, j0) != (i, j) {
+                                steps.push((cost[c - 1][i0][j0].0 + CENTER_COST, Step::Center(i0, j0)));
+                            }
+                        }
+                    }
+
+                    for (previous, step) in steps {
+                        let total = previous + across(i, j);
+
+                        if total < cost[c][i][j].0 {
+                            cost[c][i][j] = (total, step);
+                        }
+                    }
+                }
+            }
+        }
+
+        for c in 0..3 {
+            let total = cost[c][n][m].0;
+
+            if best.as_ref().is_some_and(|(best, _, _)| *best <= total) {
+                continue;
+            }
+
+            let mut triangles = Vec::new();
+            let mut band_centers = Vec::new();
+            let (mut c, mut i, mut j) = (c, n, m);
+
+            while (i, j) != (0, 0) {
+                match cost[c][i][j].1 {
+                    Step::From => {
+                        triangles.push([a(i - 1), a(i), b(j)]);
+                        i -= 1;
+                    }
+                    Step::To => {
+                        triangles.push([b(j - 1), a(i), b(j)]);
+                        j -= 1;
+                    }
+                    Step::Center(i0, j0) => {
+                        let center = CENTER + band_centers.len() as i8;
+                        let link: Vec<i8> = (i0..=i).map(a).chain((j0..=j).map(b)).collect();
+                        band_centers.push(average(values, &link));
+
+                        triangles.push([a(i), b(j), center]);
+                        triangles.extend((j0..j).map(|k| [b(k + 1), b(k), center]));
+                        triangles.extend((i0..i).map(|k| [a(k), a(k + 1), center]));
+                        triangles.push([b(j0), a(i0), center]);
+                        (c, i, j) = (c - 1, i0, j0);
+                    }
+                }
+            }
+
+            best = Some((total, triangles, band_centers));
+        }
+    }
+
+    let (_, triangles, band_centers) = best.unwrap();
+    let offset = centers.len() as i8;
+
+    for triangle in triangles.into_iter().rev() {
+        push(triangle.map(|edge| if edge >= CENTER { edge + offset } else { edge }));
+    }
+
+    centers.extend(band_centers);
+}
+
+// The average of some crossings, where a center fanned to them is put.
+fn average<T: Real>(values: &[T; 8], edges: &[i8]) -> Vec3 {
+    edges.iter().fold(Vec3::ZERO, |sum, &edge| sum + crossing(values, edge)) / edges.len() as f32
+}
+
+// Where the surface crosses an edge, relative to the first corner of the
+// cube. Values are distances from the level, as in `triangulate`.
+fn crossing<T: Real>(values: &[T; 8], edge: i8) -> Vec3 {
+    let (a, b) = EDGES[edge as usize];
+    let (pos_a, pos_b) = (point(a), point(b));
+
+    pos_a + (pos_b - pos_a) * (values[a] / (values[a] - values[b])).to_f32()
+}
+
+fn point(corner: usize) -> Vec3 {
+    let (x, y, z) = POINTS[corner];
+    Vec3::new(x as f32, y as f32, z as f32)
+}
@@ -0,0 +1,76 @@
+use std::{ops::Range, thread};
+
+use super::*;
+
+use crate::SyncScalarField;
+
+// Meshes one volume as `threads` slabs of z-layers in parallel. The slabs
+// are welded along the planes they share, and vertices are numbered as if
+// the volume was meshed in one go, so the result is identical to
+// `marching_cubes` no matter the number of threads.
+pub fn marching_cubes_parallel<T: Real>(
+    grid: Grid<T>,
+    scalar_field: &(impl SyncScalarField<T> + ?Sized),
+    isolevel: Isolevel<T>,
+    topology: Topology,
+    normals: Normals,
+    threads: usize,
+) -> MeshData<T> {
+    let voxel_grid = VoxelGrid::from_scalar_field_parallel(grid, scalar_field, threads);
+    let active_blocks = voxel_grid.min_max_pyramid().active_blocks(isolevel);
+
+    let slabs: Vec<Slab<T>> = thread::scope(|scope| {
+        let handles: Vec<_> = split_layers(grid.size[2], threads)
+            .into_iter()
+            .map(|layers| {
+                let (voxel_grid, active_blocks) = (&voxel_grid, &active_blocks);
+                scope.spawn(move || march_slab(voxel_grid, active_blocks, isolevel, topology, layers))
+            })
+            .collect();
+
+        handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+    });
+
+    let mut positions = Vec::<[T; 3]>::new();
+    let mut indices = Vec::<u32>::new();
+
+    // Top plane and local-to-global vertex indices of the previous slab.
+    let mut previous: Option<(Vec<u32>, Vec<u32>)> = None;
+
+    for slab in slabs {
+        let mut remap = vec![EdgeCache::EMPTY; slab.positions.len()];
+
+        // Every edge crossing a shared plane is used by the cubes on both
+        // sides of it, so the previous slab already made its vertex.
+        if let Some((top, previous_remap)) = &previous {
+            for (&below, &above) in top.iter().zip(slab.bottom.iter()) {
+                if above != EdgeCache::EMPTY {
+                    debug_assert_ne!(below, EdgeCache::EMPTY);
+                    remap[above as usize] = previous_remap[below as usize];
+                }
+            }
+        }
+
+        for (i, position) in slab.positions.iter().enumerate() {
+            if remap[i] == EdgeCache::EMPTY {
+                remap[i] = positions.len() as u32;
+                positions.push(*position);
+            }
+        }
+
+        indices.extend(slab.indices.iter().map(|&i| remap[i as usize]));
+
+        previous = Some((slab.top, remap));
+    }
+
+    let normals = calculate_normals(normals, &positions, &indices, &voxel_grid, isolevel);
+
+    MeshData::new(positions, normals, indices)
+}
+
+// Splits `layers` into at most `parts` contiguous, non-empty ranges.
+fn split_layers(layers: usize, parts: usize) -> Vec<Range<usize>> {
+    let parts = parts.clamp(1, layers.max(1));
+
+    (0..parts).map(|i| i * layers / parts..(i + 1) * layers / parts).collect()
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use glam::Vec3;
+
+use super::{calculate_angle_normals, calculate_smooth_normals, triangulate_cube, Isolevel, Normals, Topology};
+use crate::interval::{Interval, IntervalField};
+use crate::{Grid, MeshData, Real};
+
+// Boxes of cubes are split until they are at most this many cubes along
+// each side. Their grid points are then sampled and their cubes marched.
+const LEAF_SIZE: usize = 8;
+
+// Same triangles as `marching_cubes`, but the grid is split into boxes of
+// cubes, and boxes that `scalar_field` bounds entirely on one side of the
+// isolevel are dropped without sampling them. Samples are only kept for
+// one box at a time, so grids far too large to sample whole can be meshed
+// as long as their surface is small enough.
+//
+// `Normals::Gradient` takes central differences of the field itself
+// rather than of the samples, since those are gone by then.
+pub fn marching_cubes_pruned<T: Real>(
+    grid: Grid<T>,
+    scalar_field: &(impl IntervalField<T> + ?Sized),
+    isolevel: Isolevel<T>,
+    topology: Topology,
+    normals: Normals,
+) -> MeshData<T> {
+    let mut mesher = Mesher {
+        grid,
+        scalar_field,
+        isolevel,
+        topology,
+        positions: Vec::new(),
+        indices: Vec::new(),
+        vertices: HashMap::new(),
+    };
+
+    mesher.subdivide([0; 3], grid.size);
+
+    let Mesher { mut positions, indices, .. } = mesher;
+
+    for position in positions.iter_mut() {
+        *position = grid.to_world(*position);
+    }
+
+    let normals = match normals {
+        Normals::Area => calculate_smooth_normals(&positions, &indices),
+        Normals::Angle => calculate_angle_normals(&positions, &indices),
+        Normals::Gradient { epsilon } => positions
+            .iter()
+            .map(|&position| field_normal(scalar_field, grid, isolevel, position, epsilon))
+            .collect(),
+    };
+
+    MeshData::new(positions, normals, indices)
+}
+
+struct Mesher<'a, T: Real, F: IntervalField<T> + ?Sized> {
+    grid: Grid<T>,
+    scalar_field: &'a F,
+    isolevel: Isolevel<T>,
+    topology: Topology,
+    // In grid coordinates until all boxes are marched.
+    positions: Vec<[T; 3]>,
+    indices: Vec<u32>,
+    // Vertex indices addressed by doubled edge-midpoint coordinates, so
+    // that boxes share the vertices on their common faces.
+    vertices: HashMap<(usize, usize, usize), u32>,
+}
+
+impl<T: Real, F: IntervalField<T> + ?Sized> Mesher<'_, T, F> {
+    // Meshes the cubes from `low` up to, but not including, `high`.
+    fn subdivide(&mut self, low: [usize; 3], high: [usize; 3]) {
+        if (0..3).any(|axis| high[axis] <= low[axis]) {
+            return;
+        }
+
+        let [min_x, min_y, min_z] = self.grid.to_world(Grid::point(low));
+        let [max_x, max_y, max_z] = self.grid.to_world(Grid::point(high));
+
+        let bounds = self.scalar_field.interval(
+            Interval::between(min_x, max_x),
+            Interval::between(min_y, max_y),
+            Interval::between(min_z, max_z),
+        );
+
+        // Every sample in the box is on the same side of the isolevel.
+        if self.isolevel.is_inside(bounds.min) == self.isolevel.is_inside(bounds.max) {
+            return;
+        }
+
+        let extent: [usize; 3] = std::array::from_fn(|axis| high[axis] - low[axis]);
+        let axis = (0..3).max_by_key(|&axis| extent[axis]).unwrap();
+
+        if extent[axis] <= LEAF_SIZE {
+            self.march_box(low, high);
+            return;
+        }
+
+        let mut middle = high;
+        middle[axis] = low[axis] + extent[axis] / 2;
+        self.subdivide(low, middle);
+
+        let mut middle = low;
+        middle[axis] = low[axis] + extent[axis] / 2;
+        self.subdivide(middle, high);
+    }
+
+    fn march_box(&mut self, low: [usize; 3], high: [usize; 3]) {
+        let dimensions: [usize; 3] = std::array::from_fn(|axis| high[axis] - low[axis] + 1);
+
+        // Sampled at the same world positions as `VoxelGrid` would, so that
+        // neighbouring boxes agree on the points they share.
+        let mut samples = vec![T::ZERO; dimensions[0] * dimensions[1] * dimensions[2]];
+        self.scalar_field.sample_block(self.grid, low, dimensions, &mut samples);
+
+        let read = |x: usize, y: usize, z: usize| {
+            samples[(x - low[0]) + (y - low[1]) * dimensions[0] + (z - low[2]) * dimensions[0] * dimensions[1]]
+        };
+
+        for z in low[2]..high[2] {
+            for y in low[1]..high[1] {
+                for x in low[0]..high[0] {
+                    triangulate_cube(
+                        (x, y, z),
+                        read,
+                        self.isolevel,
+                        self.topology,
+                        &mut self.positions,
+                        &mut self.indices,
+                        &mut self.vertices,
+                    );
+                }
+            }
+        }
+    }
+}
+
+// Central differences of the field `epsilon` grid steps to either side of
+// a position, pointing away from the inside.
+fn field_normal<T: Real>(
+    scalar_field: &(impl IntervalField<T> + ?Sized),
+    grid: Grid<T>,
+    isolevel: Isolevel<T>,
+    [x, y, z]: [T; 3],
+    epsilon: usize,
+) -> [f32; 3] {
+    let step = grid.spacing.map(|spacing| spacing * T::from_usize(epsilon.max(1)));
+    let two = T::ONE + T::ONE;
+
+    let gradient = [
+        (scalar_field.sample(x + step[0], y, z) - scalar_field.sample(x - step[0], y, z)) / (two * step[0]),
+        (scalar_field.sample(x, y + step[1], z) - scalar_field.sample(x, y - step[1], z)) / (two * step[1]),
+        (scalar_field.sample(x, y, z + step[2]) - scalar_field.sample(x, y, z - step[2])) / (two * step[2]),
+    ];
+
+    Vec3::from(isolevel.orient(gradient).map(T::to_f32)).normalize_or_zero().into()
+}
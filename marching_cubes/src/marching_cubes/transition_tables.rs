@@ -0,0 +1,567 @@
+// Tables for the transition cells of `transvoxel`, which join a face of a
+// block to a neighbour with half its resolution. A transition cell spans
+// 2x2 cells of the face. Its full-resolution face has the 9 samples of those
+// cells, its half-resolution face the 4 corner samples again, and it is
+// triangulated so that the half-resolution face is cut exactly like the
+// face of a regular cube of the coarse neighbour.
+
+// Indices to the transition edge array that should be added to the vertex
+// array, for each configuration of the 9 full-resolution samples (bit i set
+// if sample i is inside). Triangles wind counter-clockwise seen from outside
+// when u, v and w (from the half- to the full-resolution face) form a
+// right-handed frame. -1 is used as a sentinel value (return/break when found).
+pub const TRANSITION_TRIANGULATIONS: [[i8; 27]; 512] = [
+    [-1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  6,  1,  6, 14,  1, 14, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 15,  0, 15, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 10,  0, 10,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 6, 14, 15,  6, 15, 10,  6, 10,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  7,  0,  7, 14,  0, 14, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  2,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  2,  1,  2,  7,  1,  7, 14,  1, 14, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 10,  2,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  7,  0,  7, 14,  0, 14, 15,  0, 15, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 10,  0, 10,  8,  2,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  7, 14,  2, 14, 15,  2, 15, 10,  2, 10,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  8,  3,  2,  3,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 12,  2,  8,  3,  2,  3,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  3,  0,  3,  9,  0,  9,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  3,  9,  1,  9,  2,  1,  2,  6,  1,  6, 14,  1, 14, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 10,  2,  8,  3,  2,  3,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 15,  0, 15, 10,  0, 10,  1,  2,  8,  3,  2,  3,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 10,  0, 10,  3,  0,  3,  9,  0,  9,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  6, 14,  2, 14, 15,  2, 15, 10,  2, 10,  3,  2,  3,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3,  9,  7,  3,  7,  6,  3,  6,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  3,  0,  3,  9,  0,  9,  7,  0,  7, 14,  0, 14, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  3,  0,  3,  9,  0,  9,  7,  0,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  3,  9,  1,  9,  7,  1,  7, 14,  1, 14, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 10,  3,  9,  7,  3,  7,  6,  3,  6,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  3,  0,  3,  9,  0,  9,  7,  0,  7, 14,  0, 14, 15,  0, 15, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 10,  0, 10,  3,  0,  3,  9,  0,  9,  7,  0,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3,  9,  7,  3,  7, 14,  3, 14, 15,  3, 15, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 12,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  6,  1,  6, 14,  1, 14, 12,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 11,  1, 11,  3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 15,  0, 15, 11,  0, 11,  3,  0,  3,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 11,  0, 11,  3,  0,  3,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3,  8,  6,  3,  6, 14,  3, 14, 15,  3, 15, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  7,  6,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  7,  0,  7, 14,  0, 14, 12,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  2,  7,  6,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  2,  1,  2,  7,  1,  7, 14,  1, 14, 12,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 11,  1, 11,  3,  2,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  7,  0,  7, 14,  0, 14, 15,  0, 15, 11,  0, 11,  3,  0,  3,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 11,  0, 11,  3,  0,  3,  8,  2,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  7, 14,  2, 14, 15,  2, 15, 11,  2, 11,  3,  2,  3,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  8, 10,  2, 10, 11,  2, 11,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 12,  2,  8, 10,  2, 10, 11,  2, 11,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1, 10,  0, 10, 11,  0, 11,  9,  0,  9,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 10, 11,  1, 11,  9,  1,  9,  2,  1,  2,  6,  1,  6, 14,  1, 14, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 11,  1, 11,  9,  1,  9,  2,  1,  2,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [14, 15, 11, 14, 11,  9, 14,  9,  2, 14,  2,  8, 14,  8,  1, 14,  1,  0, 14,  0,  6, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 11,  0, 11,  9,  0,  9,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  6, 14,  2, 14, 15,  2, 15, 11,  2, 11,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 6,  8, 10,  6, 10, 11,  6, 11,  9,  6,  9,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8, 10,  0, 10, 11,  0, 11,  9,  0,  9,  7,  0,  7, 14,  0, 14, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1, 10,  0, 10, 11,  0, 11,  9,  0,  9,  7,  0,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 10, 11,  1, 11,  9,  1,  9,  7,  1,  7, 14,  1, 14, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 11,  1, 11,  9,  1,  9,  7,  1,  7,  6,  1,  6,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  1,  7, 14, 15,  7, 15, 11,  7, 11,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 11,  0, 11,  9,  0,  9,  7,  0,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 7, 14, 15,  7, 15, 11,  7, 11,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 4, 13, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  4,  0,  4, 13,  0, 13, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  4, 13, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  6,  1,  6,  7,  1,  7,  4,  1,  4, 13,  1, 13, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 10,  4, 13, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  4,  0,  4, 13,  0, 13, 15,  0, 15, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 10,  0, 10,  8,  4, 13, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 4, 13, 15,  4, 15, 10,  4, 10,  8,  4,  8,  6,  4,  6,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  4, 13,  2, 13, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  4,  0,  4, 13,  0, 13, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  2,  4, 13,  2, 13, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  2,  1,  2,  4,  1,  4, 13,  1, 13, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 10,  2,  4, 13,  2, 13, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  4,  0,  4, 13,  0, 13, 15,  0, 15, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 10,  0, 10,  8,  2,  4, 13,  2, 13, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  4, 13,  2, 13, 15,  2, 15, 10,  2, 10,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  8,  3,  2,  3,  9,  4, 13, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  4,  0,  4, 13,  0, 13, 12,  2,  8,  3,  2,  3,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  3,  0,  3,  9,  0,  9,  2,  4, 13, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  3,  9,  1,  9,  2,  1,  2,  6,  1,  6,  7,  1,  7,  4,  1,  4, 13,  1, 13, 12, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 10,  2,  8,  3,  2,  3,  9,  4, 13, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  4,  0,  4, 13,  0, 13, 15,  0, 15, 10,  0, 10,  1,  2,  8,  3,  2,  3,  9, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 10,  0, 10,  3,  0,  3,  9,  0,  9,  2,  4, 13, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1 ],
+    [ 6,  7,  4,  6,  4, 13,  6, 13, 15,  6, 15, 10,  6, 10,  3,  6,  3,  9,  6,  9,  2, -1, -1, -1, -1, -1, -1 ],
+    [ 3,  9,  4,  3,  4, 13,  3, 13, 14,  3, 14,  6,  3,  6,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  3,  0,  3,  9,  0,  9,  4,  0,  4, 13,  0, 13, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  3,  0,  3,  9,  0,  9,  4,  0,  4, 13,  0, 13, 14,  0, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  3,  9,  1,  9,  4,  1,  4, 13,  1, 13, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 10,  3,  9,  4,  3,  4, 13,  3, 13, 14,  3, 14,  6,  3,  6,  8, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  3,  0,  3,  9,  0,  9,  4,  0,  4, 13,  0, 13, 15,  0, 15, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 10,  0, 10,  3,  0,  3,  9,  0,  9,  4,  0,  4, 13,  0, 13, 14,  0, 14,  6, -1, -1, -1 ],
+    [ 3,  9,  4,  3,  4, 13,  3, 13, 15,  3, 15, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3, 10, 11,  4, 13, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  4,  0,  4, 13,  0, 13, 12,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  3, 10, 11,  4, 13, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  6,  1,  6,  7,  1,  7,  4,  1,  4, 13,  1, 13, 12,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 11,  1, 11,  3,  4, 13, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  4,  0,  4, 13,  0, 13, 15,  0, 15, 11,  0, 11,  3,  0,  3,  1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 11,  0, 11,  3,  0,  3,  8,  4, 13, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3,  8,  6,  3,  6,  7,  3,  7,  4,  3,  4, 13,  3, 13, 15,  3, 15, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  4, 13,  2, 13, 14,  2, 14,  6,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  4,  0,  4, 13,  0, 13, 12,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  2,  4, 13,  2, 13, 14,  2, 14,  6,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  2,  1,  2,  4,  1,  4, 13,  1, 13, 12,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 11,  1, 11,  3,  2,  4, 13,  2, 13, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  4,  0,  4, 13,  0, 13, 15,  0, 15, 11,  0, 11,  3,  0,  3,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 11,  0, 11,  3,  0,  3,  8,  2,  4, 13,  2, 13, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  4, 13,  2, 13, 15,  2, 15, 11,  2, 11,  3,  2,  3,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  8, 10,  2, 10, 11,  2, 11,  9,  4, 13, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  4,  0,  4, 13,  0, 13, 12,  2,  8, 10,  2, 10, 11,  2, 11,  9, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1, 10,  0, 10, 11,  0, 11,  9,  0,  9,  2,  4, 13, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 10, 11,  1, 11,  9,  1,  9,  2,  1,  2,  6,  1,  6,  7,  1,  7,  4,  1,  4, 13,  1, 13, 12, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 11,  1, 11,  9,  1,  9,  2,  1,  2,  8,  4, 13, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1 ],
+    [13, 15, 11, 13, 11,  9, 13,  9,  2, 13,  2,  8, 13,  8,  1, 13,  1,  0, 13,  0,  6, 13,  6,  7, 13,  7,  4 ],
+    [ 0, 12, 15,  0, 15, 11,  0, 11,  9,  0,  9,  2,  4, 13, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 6,  7,  4,  6,  4, 13,  6, 13, 15,  6, 15, 11,  6, 11,  9,  6,  9,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 4, 13, 14,  4, 14,  6,  4,  6,  8,  4,  8, 10,  4, 10, 11,  4, 11,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8, 10,  0, 10, 11,  0, 11,  9,  0,  9,  4,  0,  4, 13,  0, 13, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1, 10,  0, 10, 11,  0, 11,  9,  0,  9,  4,  0,  4, 13,  0, 13, 14,  0, 14,  6, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 10, 11,  1, 11,  9,  1,  9,  4,  1,  4, 13,  1, 13, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 11,  1, 11,  9,  1,  9,  4,  1,  4, 13,  1, 13, 14,  1, 14,  6,  1,  6,  8, -1, -1, -1 ],
+    [ 0,  8,  1,  4, 13, 15,  4, 15, 11,  4, 11,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 11,  0, 11,  9,  0,  9,  4,  0,  4, 13,  0, 13, 14,  0, 14,  6, -1, -1, -1, -1, -1, -1 ],
+    [ 4, 13, 15,  4, 15, 11,  4, 11,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 12,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  6,  1,  6, 14,  1, 14, 12,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 10,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 15,  0, 15, 10,  0, 10,  1,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 10,  0, 10,  8,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 4,  9,  5,  6, 14, 15,  6, 15, 10,  6, 10,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  7,  6,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  7,  0,  7, 14,  0, 14, 12,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  2,  7,  6,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  2,  1,  2,  7,  1,  7, 14,  1, 14, 12,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 10,  2,  7,  6,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  7,  0,  7, 14,  0, 14, 15,  0, 15, 10,  0, 10,  1,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 10,  0, 10,  8,  2,  7,  6,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  7, 14,  2, 14, 15,  2, 15, 10,  2, 10,  8,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  8,  3,  2,  3,  5,  2,  5,  4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 12,  2,  8,  3,  2,  3,  5,  2,  5,  4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  3,  0,  3,  5,  0,  5,  4,  0,  4,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  3,  5,  1,  5,  4,  1,  4,  2,  1,  2,  6,  1,  6, 14,  1, 14, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 10,  2,  8,  3,  2,  3,  5,  2,  5,  4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 15,  0, 15, 10,  0, 10,  1,  2,  8,  3,  2,  3,  5,  2,  5,  4, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 10,  0, 10,  3,  0,  3,  5,  0,  5,  4,  0,  4,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  6, 14,  2, 14, 15,  2, 15, 10,  2, 10,  3,  2,  3,  5,  2,  5,  4, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3,  5,  4,  3,  4,  7,  3,  7,  6,  3,  6,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  3,  0,  3,  5,  0,  5,  4,  0,  4,  7,  0,  7, 14,  0, 14, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  3,  0,  3,  5,  0,  5,  4,  0,  4,  7,  0,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  3,  5,  1,  5,  4,  1,  4,  7,  1,  7, 14,  1, 14, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 10,  3,  5,  4,  3,  4,  7,  3,  7,  6,  3,  6,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  3,  0,  3,  5,  0,  5,  4,  0,  4,  7,  0,  7, 14,  0, 14, 15,  0, 15, 10,  0, 10,  1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 10,  0, 10,  3,  0,  3,  5,  0,  5,  4,  0,  4,  7,  0,  7,  6, -1, -1, -1, -1, -1, -1 ],
+    [ 3,  5,  4,  3,  4,  7,  3,  7, 14,  3, 14, 15,  3, 15, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3, 10, 11,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 12,  3, 10, 11,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  3, 10, 11,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  6,  1,  6, 14,  1, 14, 12,  3, 10, 11,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 11,  1, 11,  3,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 15,  0, 15, 11,  0, 11,  3,  0,  3,  1,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 11,  0, 11,  3,  0,  3,  8,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3,  8,  6,  3,  6, 14,  3, 14, 15,  3, 15, 11,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  7,  6,  3, 10, 11,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  7,  0,  7, 14,  0, 14, 12,  3, 10, 11,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  2,  7,  6,  3, 10, 11,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  2,  1,  2,  7,  1,  7, 14,  1, 14, 12,  3, 10, 11,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 11,  1, 11,  3,  2,  7,  6,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  7,  0,  7, 14,  0, 14, 15,  0, 15, 11,  0, 11,  3,  0,  3,  1,  4,  9,  5, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 11,  0, 11,  3,  0,  3,  8,  2,  7,  6,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  7, 14,  2, 14, 15,  2, 15, 11,  2, 11,  3,  2,  3,  8,  4,  9,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  8, 10,  2, 10, 11,  2, 11,  5,  2,  5,  4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 12,  2,  8, 10,  2, 10, 11,  2, 11,  5,  2,  5,  4, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1, 10,  0, 10, 11,  0, 11,  5,  0,  5,  4,  0,  4,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 10, 11,  1, 11,  5,  1,  5,  4,  1,  4,  2,  1,  2,  6,  1,  6, 14,  1, 14, 12, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 11,  1, 11,  5,  1,  5,  4,  1,  4,  2,  1,  2,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [14, 15, 11, 14, 11,  5, 14,  5,  4, 14,  4,  2, 14,  2,  8, 14,  8,  1, 14,  1,  0, 14,  0,  6, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 11,  0, 11,  5,  0,  5,  4,  0,  4,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  6, 14,  2, 14, 15,  2, 15, 11,  2, 11,  5,  2,  5,  4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 4,  7,  6,  4,  6,  8,  4,  8, 10,  4, 10, 11,  4, 11,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8, 10,  0, 10, 11,  0, 11,  5,  0,  5,  4,  0,  4,  7,  0,  7, 14,  0, 14, 12, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1, 10,  0, 10, 11,  0, 11,  5,  0,  5,  4,  0,  4,  7,  0,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 10, 11,  1, 11,  5,  1,  5,  4,  1,  4,  7,  1,  7, 14,  1, 14, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 11,  1, 11,  5,  1,  5,  4,  1,  4,  7,  1,  7,  6,  1,  6,  8, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  1,  4,  7, 14,  4, 14, 15,  4, 15, 11,  4, 11,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 11,  0, 11,  5,  0,  5,  4,  0,  4,  7,  0,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 4,  7, 14,  4, 14, 15,  4, 15, 11,  4, 11,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 5, 13, 14,  5, 14,  7,  5,  7,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  9,  0,  9,  5,  0,  5, 13,  0, 13, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  5, 13, 14,  5, 14,  7,  5,  7,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  6,  1,  6,  7,  1,  7,  9,  1,  9,  5,  1,  5, 13,  1, 13, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 10,  5, 13, 14,  5, 14,  7,  5,  7,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  9,  0,  9,  5,  0,  5, 13,  0, 13, 15,  0, 15, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 10,  0, 10,  8,  5, 13, 14,  5, 14,  7,  5,  7,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 5, 13, 15,  5, 15, 10,  5, 10,  8,  5,  8,  6,  5,  6,  7,  5,  7,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  9,  5,  2,  5, 13,  2, 13, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  9,  0,  9,  5,  0,  5, 13,  0, 13, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  2,  9,  5,  2,  5, 13,  2, 13, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  2,  1,  2,  9,  1,  9,  5,  1,  5, 13,  1, 13, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 10,  2,  9,  5,  2,  5, 13,  2, 13, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  9,  0,  9,  5,  0,  5, 13,  0, 13, 15,  0, 15, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 10,  0, 10,  8,  2,  9,  5,  2,  5, 13,  2, 13, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  9,  5,  2,  5, 13,  2, 13, 15,  2, 15, 10,  2, 10,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  8,  3,  2,  3,  5,  2,  5, 13,  2, 13, 14,  2, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 7,  2,  8,  7,  8,  3,  7,  3,  5,  7,  5, 13,  7, 13, 12,  7, 12,  0,  7,  0,  6, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  3,  0,  3,  5,  0,  5, 13,  0, 13, 14,  0, 14,  7,  0,  7,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  3,  5,  1,  5, 13,  1, 13, 12,  2,  6,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 10,  2,  8,  3,  2,  3,  5,  2,  5, 13,  2, 13, 14,  2, 14,  7, -1, -1, -1, -1, -1, -1 ],
+    [ 7,  2,  8,  7,  8,  3,  7,  3,  5,  7,  5, 13,  7, 13, 15,  7, 15, 10,  7, 10,  1,  7,  1,  0,  7,  0,  6 ],
+    [ 0, 12, 15,  0, 15, 10,  0, 10,  3,  0,  3,  5,  0,  5, 13,  0, 13, 14,  0, 14,  7,  0,  7,  2, -1, -1, -1 ],
+    [ 2,  6,  7,  3,  5, 13,  3, 13, 15,  3, 15, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3,  5, 13,  3, 13, 14,  3, 14,  6,  3,  6,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  3,  0,  3,  5,  0,  5, 13,  0, 13, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  3,  0,  3,  5,  0,  5, 13,  0, 13, 14,  0, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  3,  5,  1,  5, 13,  1, 13, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 10,  3,  5, 13,  3, 13, 14,  3, 14,  6,  3,  6,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  3,  0,  3,  5,  0,  5, 13,  0, 13, 15,  0, 15, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 10,  0, 10,  3,  0,  3,  5,  0,  5, 13,  0, 13, 14,  0, 14,  6, -1, -1, -1, -1, -1, -1 ],
+    [ 3,  5, 13,  3, 13, 15,  3, 15, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3, 10, 11,  5, 13, 14,  5, 14,  7,  5,  7,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  9,  0,  9,  5,  0,  5, 13,  0, 13, 12,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  3, 10, 11,  5, 13, 14,  5, 14,  7,  5,  7,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  6,  1,  6,  7,  1,  7,  9,  1,  9,  5,  1,  5, 13,  1, 13, 12,  3, 10, 11, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 11,  1, 11,  3,  5, 13, 14,  5, 14,  7,  5,  7,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  9,  0,  9,  5,  0,  5, 13,  0, 13, 15,  0, 15, 11,  0, 11,  3,  0,  3,  1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 11,  0, 11,  3,  0,  3,  8,  5, 13, 14,  5, 14,  7,  5,  7,  9, -1, -1, -1, -1, -1, -1 ],
+    [ 8,  6,  7,  8,  7,  9,  8,  9,  5,  8,  5, 13,  8, 13, 15,  8, 15, 11,  8, 11,  3, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  9,  5,  2,  5, 13,  2, 13, 14,  2, 14,  6,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  9,  0,  9,  5,  0,  5, 13,  0, 13, 12,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  2,  9,  5,  2,  5, 13,  2, 13, 14,  2, 14,  6,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  2,  1,  2,  9,  1,  9,  5,  1,  5, 13,  1, 13, 12,  3, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 11,  1, 11,  3,  2,  9,  5,  2,  5, 13,  2, 13, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  9,  0,  9,  5,  0,  5, 13,  0, 13, 15,  0, 15, 11,  0, 11,  3,  0,  3,  1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 11,  0, 11,  3,  0,  3,  8,  2,  9,  5,  2,  5, 13,  2, 13, 14,  2, 14,  6, -1, -1, -1 ],
+    [ 2,  9,  5,  2,  5, 13,  2, 13, 15,  2, 15, 11,  2, 11,  3,  2,  3,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  8, 10,  2, 10, 11,  2, 11,  5,  2,  5, 13,  2, 13, 14,  2, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 7,  2,  8,  7,  8, 10,  7, 10, 11,  7, 11,  5,  7,  5, 13,  7, 13, 12,  7, 12,  0,  7,  0,  6, -1, -1, -1 ],
+    [ 0,  1, 10,  0, 10, 11,  0, 11,  5,  0,  5, 13,  0, 13, 14,  0, 14,  7,  0,  7,  2, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 10, 11,  1, 11,  5,  1,  5, 13,  1, 13, 12,  2,  6,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 11,  1, 11,  5,  1,  5, 13,  1, 13, 14,  1, 14,  7,  1,  7,  2,  1,  2,  8, -1, -1, -1 ],
+    [ 7,  2,  8,  7,  8,  1,  7,  1,  0,  7,  0,  6,  5, 13, 15,  5, 15, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 11,  0, 11,  5,  0,  5, 13,  0, 13, 14,  0, 14,  7,  0,  7,  2, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  6,  7,  5, 13, 15,  5, 15, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 5, 13, 14,  5, 14,  6,  5,  6,  8,  5,  8, 10,  5, 10, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8, 10,  0, 10, 11,  0, 11,  5,  0,  5, 13,  0, 13, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1, 10,  0, 10, 11,  0, 11,  5,  0,  5, 13,  0, 13, 14,  0, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 10, 11,  1, 11,  5,  1,  5, 13,  1, 13, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 15,  1, 15, 11,  1, 11,  5,  1,  5, 13,  1, 13, 14,  1, 14,  6,  1,  6,  8, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  1,  5, 13, 15,  5, 15, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 15,  0, 15, 11,  0, 11,  5,  0,  5, 13,  0, 13, 14,  0, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 5, 13, 15,  5, 15, 11, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 5, 11, 15,  5, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 12,  5, 11, 15,  5, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  5, 11, 15,  5, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  6,  1,  6, 14,  1, 14, 12,  5, 11, 15,  5, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 13,  1, 13,  5,  1,  5, 11,  1, 11, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 13,  0, 13,  5,  0,  5, 11,  0, 11, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 13,  0, 13,  5,  0,  5, 11,  0, 11, 10,  0, 10,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 5, 11, 10,  5, 10,  8,  5,  8,  6,  5,  6, 14,  5, 14, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  7,  6,  5, 11, 15,  5, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  7,  0,  7, 14,  0, 14, 12,  5, 11, 15,  5, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  2,  7,  6,  5, 11, 15,  5, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  2,  1,  2,  7,  1,  7, 14,  1, 14, 12,  5, 11, 15,  5, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 13,  1, 13,  5,  1,  5, 11,  1, 11, 10,  2,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  7,  0,  7, 14,  0, 14, 13,  0, 13,  5,  0,  5, 11,  0, 11, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 13,  0, 13,  5,  0,  5, 11,  0, 11, 10,  0, 10,  8,  2,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  7, 14,  2, 14, 13,  2, 13,  5,  2,  5, 11,  2, 11, 10,  2, 10,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  8,  3,  2,  3,  9,  5, 11, 15,  5, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 12,  2,  8,  3,  2,  3,  9,  5, 11, 15,  5, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  3,  0,  3,  9,  0,  9,  2,  5, 11, 15,  5, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  3,  9,  1,  9,  2,  1,  2,  6,  1,  6, 14,  1, 14, 12,  5, 11, 15,  5, 15, 13, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 13,  1, 13,  5,  1,  5, 11,  1, 11, 10,  2,  8,  3,  2,  3,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 13,  0, 13,  5,  0,  5, 11,  0, 11, 10,  0, 10,  1,  2,  8,  3,  2,  3,  9, -1, -1, -1 ],
+    [ 0, 12, 13,  0, 13,  5,  0,  5, 11,  0, 11, 10,  0, 10,  3,  0,  3,  9,  0,  9,  2, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  6, 14,  2, 14, 13,  2, 13,  5,  2,  5, 11,  2, 11, 10,  2, 10,  3,  2,  3,  9, -1, -1, -1, -1, -1, -1 ],
+    [ 3,  9,  7,  3,  7,  6,  3,  6,  8,  5, 11, 15,  5, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  3,  0,  3,  9,  0,  9,  7,  0,  7, 14,  0, 14, 12,  5, 11, 15,  5, 15, 13, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  3,  0,  3,  9,  0,  9,  7,  0,  7,  6,  5, 11, 15,  5, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  3,  9,  1,  9,  7,  1,  7, 14,  1, 14, 12,  5, 11, 15,  5, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 13,  1, 13,  5,  1,  5, 11,  1, 11, 10,  3,  9,  7,  3,  7,  6,  3,  6,  8, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  3,  0,  3,  9,  0,  9,  7,  0,  7, 14,  0, 14, 13,  0, 13,  5,  0,  5, 11,  0, 11, 10,  0, 10,  1 ],
+    [ 0, 12, 13,  0, 13,  5,  0,  5, 11,  0, 11, 10,  0, 10,  3,  0,  3,  9,  0,  9,  7,  0,  7,  6, -1, -1, -1 ],
+    [ 7, 14, 13,  7, 13,  5,  7,  5, 11,  7, 11, 10,  7, 10,  3,  7,  3,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3, 10, 15,  3, 15, 13,  3, 13,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 12,  3, 10, 15,  3, 15, 13,  3, 13,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  3, 10, 15,  3, 15, 13,  3, 13,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  6,  1,  6, 14,  1, 14, 12,  3, 10, 15,  3, 15, 13,  3, 13,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 13,  1, 13,  5,  1,  5,  3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 13,  0, 13,  5,  0,  5,  3,  0,  3,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 13,  0, 13,  5,  0,  5,  3,  0,  3,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3,  8,  6,  3,  6, 14,  3, 14, 13,  3, 13,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  7,  6,  3, 10, 15,  3, 15, 13,  3, 13,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  7,  0,  7, 14,  0, 14, 12,  3, 10, 15,  3, 15, 13,  3, 13,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  2,  7,  6,  3, 10, 15,  3, 15, 13,  3, 13,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  2,  1,  2,  7,  1,  7, 14,  1, 14, 12,  3, 10, 15,  3, 15, 13,  3, 13,  5, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 13,  1, 13,  5,  1,  5,  3,  2,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  7,  0,  7, 14,  0, 14, 13,  0, 13,  5,  0,  5,  3,  0,  3,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 13,  0, 13,  5,  0,  5,  3,  0,  3,  8,  2,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  7, 14,  2, 14, 13,  2, 13,  5,  2,  5,  3,  2,  3,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  8, 10,  2, 10, 15,  2, 15, 13,  2, 13,  5,  2,  5,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 12,  2,  8, 10,  2, 10, 15,  2, 15, 13,  2, 13,  5,  2,  5,  9, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1, 10,  0, 10, 15,  0, 15, 13,  0, 13,  5,  0,  5,  9,  0,  9,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 10, 15,  1, 15, 13,  1, 13,  5,  1,  5,  9,  1,  9,  2,  1,  2,  6,  1,  6, 14,  1, 14, 12, -1, -1, -1 ],
+    [ 1, 12, 13,  1, 13,  5,  1,  5,  9,  1,  9,  2,  1,  2,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [14, 13,  5, 14,  5,  9, 14,  9,  2, 14,  2,  8, 14,  8,  1, 14,  1,  0, 14,  0,  6, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 13,  0, 13,  5,  0,  5,  9,  0,  9,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  6, 14,  2, 14, 13,  2, 13,  5,  2,  5,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 5,  9,  7,  5,  7,  6,  5,  6,  8,  5,  8, 10,  5, 10, 15,  5, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8, 10,  0, 10, 15,  0, 15, 13,  0, 13,  5,  0,  5,  9,  0,  9,  7,  0,  7, 14,  0, 14, 12, -1, -1, -1 ],
+    [ 0,  1, 10,  0, 10, 15,  0, 15, 13,  0, 13,  5,  0,  5,  9,  0,  9,  7,  0,  7,  6, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 10, 15,  1, 15, 13,  1, 13,  5,  1,  5,  9,  1,  9,  7,  1,  7, 14,  1, 14, 12, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 13,  1, 13,  5,  1,  5,  9,  1,  9,  7,  1,  7,  6,  1,  6,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  1,  5,  9,  7,  5,  7, 14,  5, 14, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 13,  0, 13,  5,  0,  5,  9,  0,  9,  7,  0,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 5,  9,  7,  5,  7, 14,  5, 14, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 4,  5, 11,  4, 11, 15,  4, 15, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  4,  0,  4,  5,  0,  5, 11,  0, 11, 15,  0, 15, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  4,  5, 11,  4, 11, 15,  4, 15, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  6,  1,  6,  7,  1,  7,  4,  1,  4,  5,  1,  5, 11,  1, 11, 15,  1, 15, 12, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 14,  1, 14,  7,  1,  7,  4,  1,  4,  5,  1,  5, 11,  1, 11, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  4,  0,  4,  5,  0,  5, 11,  0, 11, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 14,  0, 14,  7,  0,  7,  4,  0,  4,  5,  0,  5, 11,  0, 11, 10,  0, 10,  8, -1, -1, -1, -1, -1, -1 ],
+    [ 4,  5, 11,  4, 11, 10,  4, 10,  8,  4,  8,  6,  4,  6,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  4,  5,  2,  5, 11,  2, 11, 15,  2, 15, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  4,  0,  4,  5,  0,  5, 11,  0, 11, 15,  0, 15, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  2,  4,  5,  2,  5, 11,  2, 11, 15,  2, 15, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  2,  1,  2,  4,  1,  4,  5,  1,  5, 11,  1, 11, 15,  1, 15, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 14,  1, 14,  6,  1,  6,  2,  1,  2,  4,  1,  4,  5,  1,  5, 11,  1, 11, 10, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  4,  0,  4,  5,  0,  5, 11,  0, 11, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [12, 14,  6, 12,  6,  2, 12,  2,  4, 12,  4,  5, 12,  5, 11, 12, 11, 10, 12, 10,  8, 12,  8,  0, -1, -1, -1 ],
+    [ 2,  4,  5,  2,  5, 11,  2, 11, 10,  2, 10,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  8,  3,  2,  3,  9,  4,  5, 11,  4, 11, 15,  4, 15, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  4,  0,  4,  5,  0,  5, 11,  0, 11, 15,  0, 15, 12,  2,  8,  3,  2,  3,  9, -1, -1, -1 ],
+    [ 0,  1,  3,  0,  3,  9,  0,  9,  2,  4,  5, 11,  4, 11, 15,  4, 15, 14,  4, 14,  7, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  3,  9,  1,  9,  2,  1,  2,  6,  1,  6,  7,  1,  7,  4,  1,  4,  5,  1,  5, 11,  1, 11, 15,  1, 15, 12 ],
+    [ 1, 12, 14,  1, 14,  7,  1,  7,  4,  1,  4,  5,  1,  5, 11,  1, 11, 10,  2,  8,  3,  2,  3,  9, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  4,  0,  4,  5,  0,  5, 11,  0, 11, 10,  0, 10,  1,  2,  8,  3,  2,  3,  9, -1, -1, -1 ],
+    [ 0, 12, 14,  0, 14,  7,  0,  7,  4,  0,  4,  5,  0,  5, 11,  0, 11, 10,  0, 10,  3,  0,  3,  9,  0,  9,  2 ],
+    [ 6,  7,  4,  6,  4,  5,  6,  5, 11,  6, 11, 10,  6, 10,  3,  6,  3,  9,  6,  9,  2, -1, -1, -1, -1, -1, -1 ],
+    [ 4,  5, 11,  4, 11, 15,  4, 15, 14,  4, 14,  6,  4,  6,  8,  4,  8,  3,  4,  3,  9, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  3,  0,  3,  9,  0,  9,  4,  0,  4,  5,  0,  5, 11,  0, 11, 15,  0, 15, 12, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  3,  0,  3,  9,  0,  9,  4,  0,  4,  5,  0,  5, 11,  0, 11, 15,  0, 15, 14,  0, 14,  6, -1, -1, -1 ],
+    [ 1,  3,  9,  1,  9,  4,  1,  4,  5,  1,  5, 11,  1, 11, 15,  1, 15, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [12, 14,  6, 12,  6,  8, 12,  8,  3, 12,  3,  9, 12,  9,  4, 12,  4,  5, 12,  5, 11, 12, 11, 10, 12, 10,  1 ],
+    [ 0,  8,  3,  0,  3,  9,  0,  9,  4,  0,  4,  5,  0,  5, 11,  0, 11, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 14,  0, 14,  6,  4,  5, 11,  4, 11, 10,  4, 10,  3,  4,  3,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 4,  5, 11,  4, 11, 10,  4, 10,  3,  4,  3,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3, 10, 15,  3, 15, 14,  3, 14,  7,  3,  7,  4,  3,  4,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  4,  0,  4,  5,  0,  5,  3,  0,  3, 10,  0, 10, 15,  0, 15, 12, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  3, 10, 15,  3, 15, 14,  3, 14,  7,  3,  7,  4,  3,  4,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 6,  7,  4,  6,  4,  5,  6,  5,  3,  6,  3, 10,  6, 10, 15,  6, 15, 12,  6, 12,  1,  6,  1,  8, -1, -1, -1 ],
+    [ 1, 12, 14,  1, 14,  7,  1,  7,  4,  1,  4,  5,  1,  5,  3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  4,  0,  4,  5,  0,  5,  3,  0,  3,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 14,  0, 14,  7,  0,  7,  4,  0,  4,  5,  0,  5,  3,  0,  3,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3,  8,  6,  3,  6,  7,  3,  7,  4,  3,  4,  5, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  4,  5,  2,  5,  3,  2,  3, 10,  2, 10, 15,  2, 15, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  4,  0,  4,  5,  0,  5,  3,  0,  3, 10,  0, 10, 15,  0, 15, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  2,  4,  5,  2,  5,  3,  2,  3, 10,  2, 10, 15,  2, 15, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  4,  5,  2,  5,  3,  2,  3, 10,  2, 10, 15,  2, 15, 12,  2, 12,  1,  2,  1,  8, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 14,  1, 14,  6,  1,  6,  2,  1,  2,  4,  1,  4,  5,  1,  5,  3, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  4,  0,  4,  5,  0,  5,  3,  0,  3,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [12, 14,  6, 12,  6,  2, 12,  2,  4, 12,  4,  5, 12,  5,  3, 12,  3,  8, 12,  8,  0, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  4,  5,  2,  5,  3,  2,  3,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 8, 10, 15,  8, 15, 14,  8, 14,  7,  8,  7,  4,  8,  4,  5,  8,  5,  9,  8,  9,  2, -1, -1, -1, -1, -1, -1 ],
+    [ 5,  9,  2,  5,  2,  8,  5,  8, 10,  5, 10, 15,  5, 15, 12,  5, 12,  0,  5,  0,  6,  5,  6,  7,  5,  7,  4 ],
+    [ 0,  1, 10,  0, 10, 15,  0, 15, 14,  0, 14,  7,  0,  7,  4,  0,  4,  5,  0,  5,  9,  0,  9,  2, -1, -1, -1 ],
+    [ 1, 10, 15,  1, 15, 12,  6,  7,  4,  6,  4,  5,  6,  5,  9,  6,  9,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 14,  1, 14,  7,  1,  7,  4,  1,  4,  5,  1,  5,  9,  1,  9,  2,  1,  2,  8, -1, -1, -1, -1, -1, -1 ],
+    [ 5,  9,  2,  5,  2,  8,  5,  8,  1,  5,  1,  0,  5,  0,  6,  5,  6,  7,  5,  7,  4, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 14,  0, 14,  7,  0,  7,  4,  0,  4,  5,  0,  5,  9,  0,  9,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 6,  7,  4,  6,  4,  5,  6,  5,  9,  6,  9,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 4,  5,  9,  6,  8, 10,  6, 10, 15,  6, 15, 14, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8, 10,  0, 10, 15,  0, 15, 12,  4,  5,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1, 10,  0, 10, 15,  0, 15, 14,  0, 14,  6,  4,  5,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 10, 15,  1, 15, 12,  4,  5,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 14,  1, 14,  6,  1,  6,  8,  4,  5,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  1,  4,  5,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 14,  0, 14,  6,  4,  5,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 4,  5,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 4,  9, 11,  4, 11, 15,  4, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 12,  4,  9, 11,  4, 11, 15,  4, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  4,  9, 11,  4, 11, 15,  4, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  6,  1,  6, 14,  1, 14, 12,  4,  9, 11,  4, 11, 15,  4, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 13,  1, 13,  4,  1,  4,  9,  1,  9, 11,  1, 11, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 13,  0, 13,  4,  0,  4,  9,  0,  9, 11,  0, 11, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 13,  0, 13,  4,  0,  4,  9,  0,  9, 11,  0, 11, 10,  0, 10,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 4,  9, 11,  4, 11, 10,  4, 10,  8,  4,  8,  6,  4,  6, 14,  4, 14, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  7,  6,  4,  9, 11,  4, 11, 15,  4, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  7,  0,  7, 14,  0, 14, 12,  4,  9, 11,  4, 11, 15,  4, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  2,  7,  6,  4,  9, 11,  4, 11, 15,  4, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  2,  1,  2,  7,  1,  7, 14,  1, 14, 12,  4,  9, 11,  4, 11, 15,  4, 15, 13, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 13,  1, 13,  4,  1,  4,  9,  1,  9, 11,  1, 11, 10,  2,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  7,  0,  7, 14,  0, 14, 13,  0, 13,  4,  0,  4,  9,  0,  9, 11,  0, 11, 10,  0, 10,  1, -1, -1, -1 ],
+    [ 0, 12, 13,  0, 13,  4,  0,  4,  9,  0,  9, 11,  0, 11, 10,  0, 10,  8,  2,  7,  6, -1, -1, -1, -1, -1, -1 ],
+    [14, 13,  4, 14,  4,  9, 14,  9, 11, 14, 11, 10, 14, 10,  8, 14,  8,  2, 14,  2,  7, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  8,  3,  2,  3, 11,  2, 11, 15,  2, 15, 13,  2, 13,  4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 12,  2,  8,  3,  2,  3, 11,  2, 11, 15,  2, 15, 13,  2, 13,  4, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  3,  0,  3, 11,  0, 11, 15,  0, 15, 13,  0, 13,  4,  0,  4,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  3, 11,  1, 11, 15,  1, 15, 13,  1, 13,  4,  1,  4,  2,  1,  2,  6,  1,  6, 14,  1, 14, 12, -1, -1, -1 ],
+    [12, 13,  4, 12,  4,  2, 12,  2,  8, 12,  8,  3, 12,  3, 11, 12, 11, 10, 12, 10,  1, -1, -1, -1, -1, -1, -1 ],
+    [14, 13,  4, 14,  4,  2, 14,  2,  8, 14,  8,  3, 14,  3, 11, 14, 11, 10, 14, 10,  1, 14,  1,  0, 14,  0,  6 ],
+    [ 0, 12, 13,  0, 13,  4,  0,  4,  2,  3, 11, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  6, 14,  2, 14, 13,  2, 13,  4,  3, 11, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3, 11, 15,  3, 15, 13,  3, 13,  4,  3,  4,  7,  3,  7,  6,  3,  6,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  3,  0,  3, 11,  0, 11, 15,  0, 15, 13,  0, 13,  4,  0,  4,  7,  0,  7, 14,  0, 14, 12, -1, -1, -1 ],
+    [ 0,  1,  3,  0,  3, 11,  0, 11, 15,  0, 15, 13,  0, 13,  4,  0,  4,  7,  0,  7,  6, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  3, 11,  1, 11, 15,  1, 15, 13,  1, 13,  4,  1,  4,  7,  1,  7, 14,  1, 14, 12, -1, -1, -1, -1, -1, -1 ],
+    [12, 13,  4, 12,  4,  7, 12,  7,  6, 12,  6,  8, 12,  8,  3, 12,  3, 11, 12, 11, 10, 12, 10,  1, -1, -1, -1 ],
+    [ 0,  8,  3,  0,  3, 11,  0, 11, 10,  0, 10,  1,  4,  7, 14,  4, 14, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 13,  0, 13,  4,  0,  4,  7,  0,  7,  6,  3, 11, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3, 11, 10,  4,  7, 14,  4, 14, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3, 10, 15,  3, 15, 13,  3, 13,  4,  3,  4,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 12,  3, 10, 15,  3, 15, 13,  3, 13,  4,  3,  4,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  3, 10, 15,  3, 15, 13,  3, 13,  4,  3,  4,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  6,  1,  6, 14,  1, 14, 12,  3, 10, 15,  3, 15, 13,  3, 13,  4,  3,  4,  9, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 13,  1, 13,  4,  1,  4,  9,  1,  9,  3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 13,  0, 13,  4,  0,  4,  9,  0,  9,  3,  0,  3,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 13,  0, 13,  4,  0,  4,  9,  0,  9,  3,  0,  3,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3,  8,  6,  3,  6, 14,  3, 14, 13,  3, 13,  4,  3,  4,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  7,  6,  3, 10, 15,  3, 15, 13,  3, 13,  4,  3,  4,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  7,  0,  7, 14,  0, 14, 12,  3, 10, 15,  3, 15, 13,  3, 13,  4,  3,  4,  9, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  2,  7,  6,  3, 10, 15,  3, 15, 13,  3, 13,  4,  3,  4,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  2,  1,  2,  7,  1,  7, 14,  1, 14, 12,  3, 10, 15,  3, 15, 13,  3, 13,  4,  3,  4,  9, -1, -1, -1 ],
+    [ 1, 12, 13,  1, 13,  4,  1,  4,  9,  1,  9,  3,  2,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  7,  0,  7, 14,  0, 14, 13,  0, 13,  4,  0,  4,  9,  0,  9,  3,  0,  3,  1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 13,  0, 13,  4,  0,  4,  9,  0,  9,  3,  0,  3,  8,  2,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [14, 13,  4, 14,  4,  9, 14,  9,  3, 14,  3,  8, 14,  8,  2, 14,  2,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  8, 10,  2, 10, 15,  2, 15, 13,  2, 13,  4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6, 14,  0, 14, 12,  2,  8, 10,  2, 10, 15,  2, 15, 13,  2, 13,  4, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1, 10,  0, 10, 15,  0, 15, 13,  0, 13,  4,  0,  4,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 10, 15,  1, 15, 13,  1, 13,  4,  1,  4,  2,  1,  2,  6,  1,  6, 14,  1, 14, 12, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 13,  1, 13,  4,  1,  4,  2,  1,  2,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [14, 13,  4, 14,  4,  2, 14,  2,  8, 14,  8,  1, 14,  1,  0, 14,  0,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 13,  0, 13,  4,  0,  4,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  6, 14,  2, 14, 13,  2, 13,  4, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 4,  7,  6,  4,  6,  8,  4,  8, 10,  4, 10, 15,  4, 15, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8, 10,  0, 10, 15,  0, 15, 13,  0, 13,  4,  0,  4,  7,  0,  7, 14,  0, 14, 12, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1, 10,  0, 10, 15,  0, 15, 13,  0, 13,  4,  0,  4,  7,  0,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 10, 15,  1, 15, 13,  1, 13,  4,  1,  4,  7,  1,  7, 14,  1, 14, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 13,  1, 13,  4,  1,  4,  7,  1,  7,  6,  1,  6,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  1,  4,  7, 14,  4, 14, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 13,  0, 13,  4,  0,  4,  7,  0,  7,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 4,  7, 14,  4, 14, 13, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 7,  9, 11,  7, 11, 15,  7, 15, 14, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  9,  0,  9, 11,  0, 11, 15,  0, 15, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  7,  9, 11,  7, 11, 15,  7, 15, 14, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  6,  1,  6,  7,  1,  7,  9,  1,  9, 11,  1, 11, 15,  1, 15, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 14,  1, 14,  7,  1,  7,  9,  1,  9, 11,  1, 11, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  9,  0,  9, 11,  0, 11, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 14,  0, 14,  7,  0,  7,  9,  0,  9, 11,  0, 11, 10,  0, 10,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 6,  7,  9,  6,  9, 11,  6, 11, 10,  6, 10,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  9, 11,  2, 11, 15,  2, 15, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  9,  0,  9, 11,  0, 11, 15,  0, 15, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  2,  9, 11,  2, 11, 15,  2, 15, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  8,  2,  1,  2,  9,  1,  9, 11,  1, 11, 15,  1, 15, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 14,  1, 14,  6,  1,  6,  2,  1,  2,  9,  1,  9, 11,  1, 11, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  9,  0,  9, 11,  0, 11, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [12, 14,  6, 12,  6,  2, 12,  2,  9, 12,  9, 11, 12, 11, 10, 12, 10,  8, 12,  8,  0, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  9, 11,  2, 11, 10,  2, 10,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  8,  3,  2,  3, 11,  2, 11, 15,  2, 15, 14,  2, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 7,  2,  8,  7,  8,  3,  7,  3, 11,  7, 11, 15,  7, 15, 12,  7, 12,  0,  7,  0,  6, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  3,  0,  3, 11,  0, 11, 15,  0, 15, 14,  0, 14,  7,  0,  7,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  3, 11,  1, 11, 15,  1, 15, 12,  2,  6,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [12, 14,  7, 12,  7,  2, 12,  2,  8, 12,  8,  3, 12,  3, 11, 12, 11, 10, 12, 10,  1, -1, -1, -1, -1, -1, -1 ],
+    [ 7,  2,  8,  7,  8,  3,  7,  3, 11,  7, 11, 10,  7, 10,  1,  7,  1,  0,  7,  0,  6, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 14,  0, 14,  7,  0,  7,  2,  3, 11, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  6,  7,  3, 11, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3, 11, 15,  3, 15, 14,  3, 14,  6,  3,  6,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  3,  0,  3, 11,  0, 11, 15,  0, 15, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  3,  0,  3, 11,  0, 11, 15,  0, 15, 14,  0, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1,  3, 11,  1, 11, 15,  1, 15, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [12, 14,  6, 12,  6,  8, 12,  8,  3, 12,  3, 11, 12, 11, 10, 12, 10,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  3,  0,  3, 11,  0, 11, 10,  0, 10,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 14,  0, 14,  6,  3, 11, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3, 11, 10, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3, 10, 15,  3, 15, 14,  3, 14,  7,  3,  7,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  9,  0,  9,  3,  0,  3, 10,  0, 10, 15,  0, 15, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  3, 10, 15,  3, 15, 14,  3, 14,  7,  3,  7,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 6,  7,  9,  6,  9,  3,  6,  3, 10,  6, 10, 15,  6, 15, 12,  6, 12,  1,  6,  1,  8, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 14,  1, 14,  7,  1,  7,  9,  1,  9,  3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  6,  7,  0,  7,  9,  0,  9,  3,  0,  3,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 14,  0, 14,  7,  0,  7,  9,  0,  9,  3,  0,  3,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 3,  8,  6,  3,  6,  7,  3,  7,  9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  9,  3,  2,  3, 10,  2, 10, 15,  2, 15, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  9,  0,  9,  3,  0,  3, 10,  0, 10, 15,  0, 15, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1,  8,  2,  9,  3,  2,  3, 10,  2, 10, 15,  2, 15, 14,  2, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  9,  3,  2,  3, 10,  2, 10, 15,  2, 15, 12,  2, 12,  1,  2,  1,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 14,  1, 14,  6,  1,  6,  2,  1,  2,  9,  1,  9,  3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  2,  9,  0,  9,  3,  0,  3,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [12, 14,  6, 12,  6,  2, 12,  2,  9, 12,  9,  3, 12,  3,  8, 12,  8,  0, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  9,  3,  2,  3,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  8, 10,  2, 10, 15,  2, 15, 14,  2, 14,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 7,  2,  8,  7,  8, 10,  7, 10, 15,  7, 15, 12,  7, 12,  0,  7,  0,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1, 10,  0, 10, 15,  0, 15, 14,  0, 14,  7,  0,  7,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 10, 15,  1, 15, 12,  2,  6,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 14,  1, 14,  7,  1,  7,  2,  1,  2,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 7,  2,  8,  7,  8,  1,  7,  1,  0,  7,  0,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 14,  0, 14,  7,  0,  7,  2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 2,  6,  7, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 6,  8, 10,  6, 10, 15,  6, 15, 14, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8, 10,  0, 10, 15,  0, 15, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  1, 10,  0, 10, 15,  0, 15, 14,  0, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 10, 15,  1, 15, 12, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 1, 12, 14,  1, 14,  6,  1,  6,  8, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0,  8,  1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [ 0, 12, 14,  0, 14,  6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+    [-1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1 ],
+];
+
+// Indices to the transition points array for the ends of each edge: the 12
+// edges between neighbouring samples of the full-resolution face, then the 4
+// edges of the half-resolution face
+pub const TRANSITION_EDGES: [(usize, usize); 16] = [
+    (0, 1),
+    (1, 2),
+    (3, 4),
+    (4, 5),
+    (6, 7),
+    (7, 8),
+    (0, 3),
+    (3, 6),
+    (1, 4),
+    (4, 7),
+    (2, 5),
+    (5, 8),
+    (9, 10),
+    (11, 12),
+    (9, 11),
+    (10, 12),
+];
+
+// Offsets (u, v) within the face of the transition cell, in cells of the
+// full-resolution face. Points 0 to 8 lie on the full-resolution face, 9 to 12
+// on the half-resolution face, where they repeat the samples of 0, 2, 6 and 8
+pub const TRANSITION_POINTS: [(usize, usize); 13] = [
+    (0, 0),
+    (1, 0),
+    (2, 0),
+    (0, 1),
+    (1, 1),
+    (2, 1),
+    (0, 2),
+    (1, 2),
+    (2, 2),
+    (0, 0),
+    (2, 0),
+    (0, 2),
+    (2, 2),
+];
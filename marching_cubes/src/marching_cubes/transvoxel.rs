@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+
+use super::march_tables::{EDGES, POINTS};
+use super::transition_tables::{TRANSITION_EDGES, TRANSITION_POINTS, TRANSITION_TRIANGULATIONS};
+use super::{calculate_normals, get_triangulation, Isolevel, Normals};
+use crate::real::{self, Real};
+use crate::{Grid, MeshData, ScalarField, VoxelGrid};
+
+// Which faces of a block border a neighbour with half its resolution, in
+// the order -x, +x, -y, +y, -z, +z, and how far transition cells reach into
+// the block, as a fraction of a cell.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transitions {
+    pub faces: [bool; 6],
+    pub width: f32,
+}
+
+impl Transitions {
+    pub fn new(faces: [bool; 6]) -> Self {
+        Self { faces, width: 0.5 }
+    }
+
+    pub fn with_width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+}
+
+impl Default for Transitions {
+    fn default() -> Self {
+        Self::new([false; 6])
+    }
+}
+
+// Like `marching_cubes` with `Topology::Classic`, but the faces marked in
+// `transitions` are joined without cracks to a neighbour meshed by
+// `marching_cubes` at half the resolution. The cells along those faces are
+// squashed to make room for a layer of transition cells, which must cover
+// 2x2 cells each, so the grid size has to be even along those faces.
+pub fn transvoxel<T: Real>(
+    grid: Grid<T>,
+    scalar_field: &(impl ScalarField<T> + ?Sized),
+    isolevel: Isolevel<T>,
+    transitions: Transitions,
+    normals: Normals,
+) -> MeshData<T> {
+    for (face, _) in transitions.faces.iter().enumerate().filter(|(_, &face)| face) {
+        let (u, v) = face_axes(face / 2);
+        assert!(
+            grid.size[u].is_multiple_of(2) && grid.size[v].is_multiple_of(2),
+            "transition face {} needs an even grid size along it, got {:?}", face, grid.size,
+        );
+    }
+
+    let voxel_grid = VoxelGrid::from_scalar_field(grid, scalar_field);
+
+    let mut mesher = Mesher {
+        voxel_grid: &voxel_grid,
+        isolevel,
+        transitions,
+        positions: Vec::new(),
+        indices: Vec::new(),
+        vertices: HashMap::new(),
+    };
+
+    for z in 0..grid.size[2] {
+        for y in 0..grid.size[1] {
+            for x in 0..grid.size[0] {
+                mesher.regular_cell((x, y, z));
+            }
+        }
+    }
+
+    for face in (0..6).filter(|&face| transitions.faces[face]) {
+        let (u, v) = face_axes(face / 2);
+
+        for j in (0..grid.size[v]).step_by(2) {
+            for i in (0..grid.size[u]).step_by(2) {
+                mesher.transition_cell(face, (i, j));
+            }
+        }
+    }
+
+    let Mesher { positions, indices, .. } = mesher;
+
+    let normals = calculate_normals(normals, &positions, &indices, &voxel_grid, isolevel);
+
+    MeshData::new(positions, normals, indices)
+}
+
+// The axes along a face perpendicular to `axis`, in the order that makes
+// them a right-handed frame together with `axis`.
+fn face_axes(axis: usize) -> (usize, usize) {
+    ((axis + 1) % 3, (axis + 2) % 3)
+}
+
+struct Mesher<'a, T: Real> {
+    voxel_grid: &'a VoxelGrid<T>,
+    isolevel: Isolevel<T>,
+    transitions: Transitions,
+    positions: Vec<[T; 3]>,
+    indices: Vec<u32>,
+    // Vertex indices addressed by doubled edge-midpoint coordinates. Edges
+    // of the half-resolution faces have all of them even, so they can't
+    // collide with the edges between neighbouring grid points.
+    vertices: HashMap<[usize; 3], u32>,
+}
+
+impl<T: Real> Mesher<'_, T> {
+    fn regular_cell(&mut self, (x, y, z): (usize, usize, usize)) {
+        let triangulation = get_triangulation((x, y, z), self.voxel_grid, self.isolevel);
+
+        for &edge_index in &triangulation {
+            if edge_index.is_negative() { break; }
+
+            let (a, b) = EDGES[edge_index as usize];
+            let (x0, y0, z0) = POINTS[a];
+            let (x1, y1, z1) = POINTS[b];
+
+            let index = self.vertex([x + x0, y + y0, z + z0], [x + x1, y + y1, z + z1]);
+            self.indices.push(index);
+        }
+    }
+
+    // The transition cell covering cells `i..i + 2` and `j..j + 2` along
+    // the axes of `face`.
+    fn transition_cell(&mut self, face: usize, (i, j): (usize, usize)) {
+        let axis = face / 2;
+        let (u, v) = face_axes(axis);
+        let depth = if face.is_multiple_of(2) { 0 } else { self.voxel_grid.grid().size[axis] };
+
+        let point = |p: usize| {
+            let (du, dv) = TRANSITION_POINTS[p];
+            let mut point = [0; 3];
+            point[axis] = depth;
+            point[u] = i + du;
+            point[v] = j + dv;
+            point
+        };
+
+        let mut config_idx = 0;
+
+        for p in 0..9 {
+            let [x, y, z] = point(p);
+            config_idx |= (self.isolevel.is_inside(self.voxel_grid.read(x, y, z)) as usize) << p;
+        }
+
+        // On the positive faces, going from the half- to the full-resolution
+        // face runs against the axis, which mirrors the frame of the table.
+        let mirrored = face % 2 == 1;
+
+        for triangle in TRANSITION_TRIANGULATIONS[config_idx].chunks_exact(3) {
+            if triangle[0].is_negative() { break; }
+
+            let mut corners = [0, 1, 2].map(|k| {
+                let (a, b) = TRANSITION_EDGES[triangle[k] as usize];
+                self.vertex(point(a), point(b))
+            });
+
+            if mirrored {
+                corners.swap(1, 2);
+            }
+
+            self.indices.extend(corners);
+        }
+    }
+
+    // The vertex where the edge from `a` to `b` crosses the isolevel.
+    fn vertex(&mut self, a: [usize; 3], b: [usize; 3]) -> u32 {
+        let key = [a[0] + b[0], a[1] + b[1], a[2] + b[2]];
+
+        if let Some(&index) = self.vertices.get(&key) {
+            return index;
+        }
+
+        let pos_a = Grid::point(a);
+        let pos_b = Grid::point(b);
+
+        let val_a = self.voxel_grid.read(a[0], a[1], a[2]);
+        let val_b = self.voxel_grid.read(b[0], b[1], b[2]);
+
+        let t = self.isolevel.crossing(val_a, val_b);
+
+        let mut position = real::add(pos_a, real::scale(real::sub(pos_b, pos_a), t));
+
+        // Edges of the half-resolution faces stay on the boundary, where
+        // the coarse neighbour puts its vertices.
+        if (0..3).map(|axis| a[axis].abs_diff(b[axis])).sum::<usize>() == 1 {
+            position = self.squash(position);
+        }
+
+        let index = self.positions.len() as u32;
+        self.positions.push(self.voxel_grid.grid().to_world(position));
+        self.vertices.insert(key, index);
+
+        index
+    }
+
+    // Moves a grid coordinate within one cell of a transition face away
+    // from it, so that the cell next to the face is left `width` thinner.
+    fn squash(&self, mut coord: [T; 3]) -> [T; 3] {
+        let size = self.voxel_grid.grid().size;
+        let width = T::from_f32(self.transitions.width);
+
+        for axis in 0..3 {
+            let end = T::from_usize(size[axis]);
+
+            if self.transitions.faces[axis * 2] && coord[axis] < T::ONE {
+                coord[axis] = width + coord[axis] * (T::ONE - width);
+            }
+
+            if self.transitions.faces[axis * 2 + 1] && coord[axis] > end - T::ONE {
+                coord[axis] = end - width - (end - coord[axis]) * (T::ONE - width);
+            }
+        }
+
+        coord
+    }
+}
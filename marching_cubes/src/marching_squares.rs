@@ -0,0 +1,376 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::voxel_grid::Voxels;
+use crate::{Isolevel, MeshData};
+
+pub type ScalarField2d = dyn Fn(f32, f32) -> f32;
+
+// Placement of a rectangle of squares in the plane. `size` counts squares,
+// so the grid has one more point than squares along each axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Grid2d {
+    pub size: [usize; 2],
+    pub origin: [f32; 2],
+    pub spacing: [f32; 2],
+}
+
+impl Grid2d {
+    pub fn new(size: [usize; 2]) -> Self {
+        Self {
+            size,
+            origin: [0.0; 2],
+            spacing: [1.0; 2],
+        }
+    }
+
+    pub fn square(resolution: usize) -> Self {
+        Self::new([resolution; 2])
+    }
+
+    pub fn with_origin(mut self, origin: [f32; 2]) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    pub fn with_spacing(mut self, spacing: [f32; 2]) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    // Number of grid points along each axis.
+    pub fn dimensions(&self) -> [usize; 2] {
+        [self.size[0] + 1, self.size[1] + 1]
+    }
+
+    // Maps a (possibly fractional) grid coordinate to the plane.
+    pub fn to_world(&self, [x, y]: [f32; 2]) -> [f32; 2] {
+        [
+            self.origin[0] + x * self.spacing[0],
+            self.origin[1] + y * self.spacing[1],
+        ]
+    }
+}
+
+// Samples of a 2D scalar field at the points of a grid, stored x-major.
+pub struct PixelGrid {
+    data: Vec<f32>,
+    grid: Grid2d,
+}
+
+impl PixelGrid {
+    // Takes samples that are already there, such as a heightmap. There
+    // must be one for every grid point.
+    pub fn new(grid: Grid2d, data: Vec<f32>) -> Self {
+        let [size_x, size_y] = grid.dimensions();
+        assert_eq!(data.len(), size_x * size_y, "expected one sample per grid point");
+
+        Self { data, grid }
+    }
+
+    // Samples `scalar_field` at the position of every grid point.
+    pub fn from_scalar_field(grid: Grid2d, scalar_field: &ScalarField2d) -> Self {
+        let [size_x, size_y] = grid.dimensions();
+
+        let data = (0..size_x * size_y)
+            .map(|i| {
+                let [wx, wy] = grid.to_world([(i % size_x) as f32, (i / size_x) as f32]);
+                scalar_field(wx, wy)
+            })
+            .collect();
+
+        Self { data, grid }
+    }
+
+    // The plane of samples at `index` along `axis` of a voxel grid. The
+    // other two axes follow in cyclic order (y and z for x, z and x for y,
+    // x and y for z), so contours keep their orientation.
+    pub fn from_voxel_slice(voxel_grid: &(impl Voxels + ?Sized), axis: usize, index: usize) -> Self {
+        let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+        let voxel = voxel_grid.grid();
+
+        let grid = Grid2d::new([voxel.size[u], voxel.size[v]])
+            .with_origin([voxel.origin[u], voxel.origin[v]])
+            .with_spacing([voxel.spacing[u], voxel.spacing[v]]);
+
+        let [size_x, size_y] = grid.dimensions();
+
+        let data = (0..size_x * size_y)
+            .map(|i| {
+                let mut point = [0; 3];
+                point[axis] = index;
+                point[u] = i % size_x;
+                point[v] = i / size_x;
+                voxel_grid.read(point[0], point[1], point[2])
+            })
+            .collect();
+
+        Self { data, grid }
+    }
+
+    pub fn grid(&self) -> Grid2d {
+        self.grid
+    }
+
+    pub fn read(&self, x: usize, y: usize) -> f32 {
+        self.data[x + y * self.grid.dimensions()[0]]
+    }
+}
+
+// How squares with two inside corners on one diagonal are cut.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Ambiguity {
+    // Always keep the inside corners apart, like the faces of the classic
+    // marching cubes table.
+    #[default]
+    Separate,
+    // Join the inside corners if the bilinear interpolant of the samples
+    // is inside at its saddle point, which follows the field more closely.
+    AsymptoticDecider,
+}
+
+// A contour line. Inside lies to its left, so closed lines run counter-
+// clockwise around inside regions and clockwise around holes. Open lines
+// start and end on the border of the grid. The first point of a closed
+// line is not repeated at its end.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Polyline {
+    pub points: Vec<[f32; 2]>,
+    pub closed: bool,
+}
+
+// Offsets of the corners of a square, counter-clockwise. Edge `i` runs
+// from corner `i` to corner `i + 1`.
+const CORNERS: [(usize, usize); 4] = [(0, 0), (1, 0), (1, 1), (0, 1)];
+
+// Same as `contour_lines`, but samples `scalar_field` first.
+pub fn marching_squares(
+    grid: Grid2d,
+    scalar_field: &ScalarField2d,
+    isolevel: Isolevel,
+    ambiguity: Ambiguity,
+) -> Vec<Polyline> {
+    contour_lines(&PixelGrid::from_scalar_field(grid, scalar_field), isolevel, ambiguity)
+}
+
+// The lines along which the samples cross the isolevel.
+pub fn contour_lines(
+    pixel_grid: &PixelGrid,
+    isolevel: Isolevel,
+    ambiguity: Ambiguity,
+) -> Vec<Polyline> {
+    let grid = pixel_grid.grid();
+
+    // Segments between crossings, addressed by doubled edge-midpoint
+    // coordinates, in the order they were found.
+    let mut segments = Vec::new();
+
+    for y in 0..grid.size[1] {
+        for x in 0..grid.size[0] {
+            let square = Square::read((x, y), pixel_grid, isolevel);
+
+            for (exit, enter) in square.segments(ambiguity) {
+                segments.push((square.edge_key(exit), square.edge_key(enter)));
+            }
+        }
+    }
+
+    let next: HashMap<_, _> = segments.iter().copied().collect();
+    let has_previous: HashSet<_> = segments.iter().map(|&(_, end)| end).collect();
+
+    let mut visited = HashSet::new();
+    let mut polylines = Vec::new();
+
+    // Open lines first, from the crossings nothing leads into, then the
+    // loops that are left.
+    let open = segments.iter().filter(|(start, _)| !has_previous.contains(start));
+    let closed = segments.iter().filter(|(start, _)| has_previous.contains(start));
+
+    for (&(start, _), closed) in open.map(|s| (s, false)).chain(closed.map(|s| (s, true))) {
+        if visited.contains(&start) {
+            continue;
+        }
+
+        let mut points = Vec::new();
+        let mut key = Some(start);
+
+        while let Some(current) = key {
+            if !visited.insert(current) {
+                break;
+            }
+
+            points.push(crossing(current, pixel_grid, isolevel));
+            key = next.get(&current).copied();
+        }
+
+        polylines.push(Polyline { points, closed });
+    }
+
+    polylines
+}
+
+// The inside region as triangles in the xy-plane, wound like the meshes of
+// the 3D meshers, with normals along +z. Triangles of neighbouring squares
+// share their vertices.
+pub fn filled_region(
+    pixel_grid: &PixelGrid,
+    isolevel: Isolevel,
+    ambiguity: Ambiguity,
+) -> MeshData {
+    let grid = pixel_grid.grid();
+
+    let mut positions = Vec::new();
+    let mut indices = Vec::new();
+
+    // Vertex indices of corners and crossings, addressed by doubled
+    // coordinates.
+    let mut vertices = HashMap::new();
+
+    let mut vertex = |key: (usize, usize)| {
+        *vertices.entry(key).or_insert_with(|| {
+            let [x, y] = if key.0 % 2 == 1 || key.1 % 2 == 1 {
+                crossing(key, pixel_grid, isolevel)
+            } else {
+                grid.to_world([(key.0 / 2) as f32, (key.1 / 2) as f32])
+            };
+
+            positions.push([x, y, 0.0]);
+            positions.len() as u32 - 1
+        })
+    };
+
+    for y in 0..grid.size[1] {
+        for x in 0..grid.size[0] {
+            let square = Square::read((x, y), pixel_grid, isolevel);
+
+            for polygon in square.inside_polygons(ambiguity) {
+                let polygon: Vec<u32> = polygon.into_iter().map(&mut vertex).collect();
+
+                // The polygons are convex, and never have their first
+                // vertex in line with two others.
+                for k in 1..polygon.len().saturating_sub(1) {
+                    indices.extend([polygon[0], polygon[k + 1], polygon[k]]);
+                }
+            }
+        }
+    }
+
+    let normals = vec![[0.0, 0.0, 1.0]; positions.len()];
+
+    MeshData::new(positions, normals, indices)
+}
+
+// Where the samples cross the isolevel along the edge with doubled
+// midpoint coordinates `key`. Neighbouring squares get the same point, as
+// the edge is always interpolated from its lower end.
+fn crossing(key: (usize, usize), pixel_grid: &PixelGrid, isolevel: Isolevel) -> [f32; 2] {
+    let (a, b) = ((key.0 / 2, key.1 / 2), (key.0.div_ceil(2), key.1.div_ceil(2)));
+
+    let t = isolevel.crossing(pixel_grid.read(a.0, a.1), pixel_grid.read(b.0, b.1));
+
+    pixel_grid.grid().to_world([
+        a.0 as f32 + (b.0 - a.0) as f32 * t,
+        a.1 as f32 + (b.1 - a.1) as f32 * t,
+    ])
+}
+
+// The samples at the corners of one square, in the order of `CORNERS`.
+struct Square {
+    coord: (usize, usize),
+    values: [f32; 4],
+    inside: [bool; 4],
+    isolevel: Isolevel,
+}
+
+impl Square {
+    fn read((x, y): (usize, usize), pixel_grid: &PixelGrid, isolevel: Isolevel) -> Self {
+        let values = CORNERS.map(|(x0, y0)| pixel_grid.read(x + x0, y + y0));
+
+        Self {
+            coord: (x, y),
+            values,
+            inside: values.map(|value| isolevel.is_inside(value)),
+            isolevel,
+        }
+    }
+
+    fn corner_key(&self, corner: usize) -> (usize, usize) {
+        let (x0, y0) = CORNERS[corner];
+        ((self.coord.0 + x0) * 2, (self.coord.1 + y0) * 2)
+    }
+
+    fn edge_key(&self, edge: usize) -> (usize, usize) {
+        let (a, b) = (self.corner_key(edge), self.corner_key((edge + 1) % 4));
+        ((a.0 + b.0) / 2, (a.1 + b.1) / 2)
+    }
+
+    fn crosses(&self, edge: usize) -> bool {
+        self.inside[edge] != self.inside[(edge + 1) % 4]
+    }
+
+    // Whether the two inside corners of an ambiguous square are joined.
+    fn joined(&self, ambiguity: Ambiguity) -> bool {
+        match ambiguity {
+            Ambiguity::Separate => false,
+            Ambiguity::AsymptoticDecider => {
+                let [a, b, c, d] = self.values;
+                self.isolevel.is_inside((a * c - b * d) / (a + c - b - d))
+            }
+        }
+    }
+
+    fn is_ambiguous(&self) -> bool {
+        self.inside == [true, false, true, false] || self.inside == [false, true, false, true]
+    }
+
+    // Pairs of edges the contour runs between, from the edge where a walk
+    // around the square leaves the inside to the one where it enters it.
+    fn segments(&self, ambiguity: Ambiguity) -> Vec<(usize, usize)> {
+        let exits = (0..4).filter(|&edge| self.crosses(edge) && self.inside[edge]);
+
+        let joined = self.is_ambiguous() && self.joined(ambiguity);
+
+        exits
+            .map(|exit| {
+                // Without a join, the contour goes back to the edge just
+                // before the run of inside corners ending at `exit`.
+                // With one, it goes on to the edge before the other run.
+                let enter = if joined {
+                    (exit + 1) % 4
+                } else {
+                    (1..4).map(|back| (exit + 4 - back) % 4).find(|&edge| self.crosses(edge)).unwrap()
+                };
+
+                (exit, enter)
+            })
+            .collect()
+    }
+
+    // The parts of the square that are inside, as corner and crossing
+    // keys, counter-clockwise.
+    fn inside_polygons(&self, ambiguity: Ambiguity) -> Vec<Vec<(usize, usize)>> {
+        if self.is_ambiguous() && !self.joined(ambiguity) {
+            return (0..4)
+                .filter(|&corner| self.inside[corner])
+                .map(|corner| vec![self.edge_key((corner + 3) % 4), self.corner_key(corner), self.edge_key(corner)])
+                .collect();
+        }
+
+        let mut polygon = Vec::new();
+
+        for corner in 0..4 {
+            if self.inside[corner] {
+                polygon.push(self.corner_key(corner));
+            }
+
+            if self.crosses(corner) {
+                polygon.push(self.edge_key(corner));
+            }
+        }
+
+        if polygon.len() < 3 {
+            return Vec::new();
+        }
+
+        vec![polygon]
+    }
+}
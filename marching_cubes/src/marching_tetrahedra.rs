@@ -0,0 +1,177 @@
+use glam::Vec3;
+
+use crate::marching_cubes::{calculate_normals, march_tables::POINTS};
+use crate::real::{self, Real};
+use crate::{Grid, Isolevel, MeshData, Normals, ScalarField, VoxelGrid};
+
+// Indices to the points array for the corners of the 6 tetrahedra a cube
+// is split into. They all share the diagonal from point 0 to point 6, so
+// neighbouring cubes split their shared faces along the same diagonal.
+pub const TETRAHEDRA: [[usize; 4]; 6] = [
+    [0, 3, 7, 6],
+    [0, 3, 2, 6],
+    [0, 4, 7, 6],
+    [0, 4, 5, 6],
+    [0, 1, 2, 6],
+    [0, 1, 5, 6],
+];
+
+// Splits every cube into `TETRAHEDRA` and cuts each of them on its own.
+// A tetrahedron is cut by at most one triangle or quad, so there are no
+// ambiguous cases, at the price of more and thinner triangles than
+// `marching_cubes`.
+pub fn marching_tetrahedra<T: Real>(
+    grid: Grid<T>,
+    scalar_field: &(impl ScalarField<T> + ?Sized),
+    isolevel: Isolevel<T>,
+    normals: Normals,
+) -> MeshData<T> {
+    let voxel_grid = VoxelGrid::from_scalar_field(grid, scalar_field);
+
+    let mut positions = Vec::new();
+    let mut indices = Vec::new();
+    let mut edge_cache = EdgeCache::new(voxel_grid.dimensions());
+
+    for z in 0..grid.size[2] {
+        edge_cache.advance(z);
+
+        for y in 0..grid.size[1] {
+            for x in 0..grid.size[0] {
+                let corners = POINTS.map(|(x0, y0, z0)| [x + x0, y + y0, z + z0]);
+
+                for tetrahedron in TETRAHEDRA {
+                    let corners = tetrahedron.map(|p| corners[p]);
+
+                    march_tetrahedron(corners, &voxel_grid, isolevel, &mut positions, &mut indices, &mut edge_cache);
+                }
+            }
+        }
+    }
+
+    let normals = calculate_normals(normals, &positions, &indices, &voxel_grid, isolevel);
+
+    MeshData::new(positions, normals, indices)
+}
+
+fn march_tetrahedron<T: Real>(
+    corners: [[usize; 3]; 4],
+    voxel_grid: &VoxelGrid<T>,
+    isolevel: Isolevel<T>,
+    positions: &mut Vec<[T; 3]>,
+    indices: &mut Vec<u32>,
+    edge_cache: &mut EdgeCache,
+) {
+    let (inside, outside): (Vec<[usize; 3]>, Vec<[usize; 3]>) = corners
+        .into_iter()
+        .partition(|&[x, y, z]| isolevel.is_inside(voxel_grid.read(x, y, z)));
+
+    // The edges the surface crosses, in order around the cut.
+    let mut edges = match (inside.as_slice(), outside.as_slice()) {
+        (&[a], &[b, c, d]) | (&[b, c, d], &[a]) => vec![(a, b), (a, c), (a, d)],
+        (&[a, b], &[c, d]) => vec![(a, c), (a, d), (b, d), (b, c)],
+        _ => return,
+    };
+
+    // The winding is decided on the edge midpoints, where the cut is never
+    // degenerate, so that it agrees with the neighbouring tetrahedra.
+    let midpoints: Vec<Vec3> = edges.iter().map(|&(a, b)| (to_vec3(a) + to_vec3(b)) / 2.0).collect();
+    let outwards = centroid(&outside) - centroid(&inside);
+
+    let n = (midpoints[2] - midpoints[0]).cross(midpoints[1] - midpoints[0]);
+
+    if n.dot(outwards) < 0.0 {
+        edges.reverse();
+    }
+
+    let vertices: Vec<u32> = edges
+        .into_iter()
+        .map(|(a, b)| vertex(a, b, voxel_grid, isolevel, positions, edge_cache))
+        .collect();
+
+    for k in 1..vertices.len() - 1 {
+        indices.extend([vertices[0], vertices[k], vertices[k + 1]]);
+    }
+}
+
+// The vertex where the edge from `a` to `b` crosses the isolevel, shared
+// by every tetrahedron around the edge. Vertices are addressed by doubled
+// edge-midpoint coordinates.
+fn vertex<T: Real>(
+    a: [usize; 3],
+    b: [usize; 3],
+    voxel_grid: &VoxelGrid<T>,
+    isolevel: Isolevel<T>,
+    positions: &mut Vec<[T; 3]>,
+    edge_cache: &mut EdgeCache,
+) -> u32 {
+    let cached_index = edge_cache.get_mut([a[0] + b[0], a[1] + b[1], a[2] + b[2]]);
+
+    if *cached_index == EdgeCache::EMPTY {
+        let pos_a = Grid::point(a);
+        let pos_b = Grid::point(b);
+
+        let val_a = voxel_grid.read(a[0], a[1], a[2]);
+        let val_b = voxel_grid.read(b[0], b[1], b[2]);
+
+        let t = isolevel.crossing(val_a, val_b);
+
+        *cached_index = positions.len() as u32;
+        positions.push(voxel_grid.grid().to_world(real::add(pos_a, real::scale(real::sub(pos_b, pos_a), t))));
+    }
+
+    *cached_index
+}
+
+// Vertex indices of the edges touching one layer of cubes, laid out like
+// the edge cache of `marching_cubes`. Every edge of `TETRAHEDRA` runs from
+// a grid point towards larger coordinates, so each grid point has three
+// edges in its plane (along x, along y, and the face diagonal between
+// them) and four to the plane above (along z, the two face diagonals with
+// a step in z, and the body diagonal).
+struct EdgeCache {
+    dimensions: [usize; 3],
+    planes: [Vec<u32>; 2],
+    vertical: Vec<u32>,
+}
+
+impl EdgeCache {
+    const EMPTY: u32 = u32::MAX;
+
+    fn new(dimensions: [usize; 3]) -> Self {
+        let plane_len = dimensions[0] * dimensions[1];
+
+        Self {
+            dimensions,
+            planes: [vec![Self::EMPTY; plane_len * 3], vec![Self::EMPTY; plane_len * 3]],
+            vertical: vec![Self::EMPTY; plane_len * 4],
+        }
+    }
+
+    // Clears the edges that layer `z` does not share with layer `z - 1`.
+    fn advance(&mut self, z: usize) {
+        self.planes[(z + 1) % 2].fill(Self::EMPTY);
+        self.vertical.fill(Self::EMPTY);
+    }
+
+    // The edge with doubled midpoint coordinates `[x, y, z]`. Its first
+    // grid point is half of that, rounded down, and its direction is what
+    // is left over.
+    fn get_mut(&mut self, [x, y, z]: [usize; 3]) -> &mut u32 {
+        let point = x / 2 + y / 2 * self.dimensions[0];
+        let direction = x % 2 + y % 2 * 2;
+
+        if z % 2 == 1 {
+            &mut self.vertical[point * 4 + direction]
+        } else {
+            &mut self.planes[z / 2 % 2][point * 3 + direction - 1]
+        }
+    }
+}
+
+fn to_vec3([x, y, z]: [usize; 3]) -> Vec3 {
+    Vec3::new(x as f32, y as f32, z as f32)
+}
+
+fn centroid(points: &[[usize; 3]]) -> Vec3 {
+    points.iter().fold(Vec3::ZERO, |sum, &point| sum + to_vec3(point)) / points.len() as f32
+}
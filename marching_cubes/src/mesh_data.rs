@@ -0,0 +1,192 @@
+use glam::{Affine3A, Vec3};
+
+use crate::real::{self, Real};
+
+// Per-vertex values of an extra attribute, such as uvs or colors.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttributeValues {
+    Float32(Vec<f32>),
+    Float32x2(Vec<[f32; 2]>),
+    Float32x3(Vec<[f32; 3]>),
+    Float32x4(Vec<[f32; 4]>),
+}
+
+impl AttributeValues {
+    pub fn len(&self) -> usize {
+        match self {
+            AttributeValues::Float32(values) => values.len(),
+            AttributeValues::Float32x2(values) => values.len(),
+            AttributeValues::Float32x3(values) => values.len(),
+            AttributeValues::Float32x4(values) => values.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // The values of the vertices at `indices`, in that order.
+    pub(crate) fn select(&self, indices: &[usize]) -> AttributeValues {
+        match self {
+            AttributeValues::Float32(values) => AttributeValues::Float32(indices.iter().map(|&i| values[i]).collect()),
+            AttributeValues::Float32x2(values) => AttributeValues::Float32x2(indices.iter().map(|&i| values[i]).collect()),
+            AttributeValues::Float32x3(values) => AttributeValues::Float32x3(indices.iter().map(|&i| values[i]).collect()),
+            AttributeValues::Float32x4(values) => AttributeValues::Float32x4(indices.iter().map(|&i| values[i]).collect()),
+        }
+    }
+
+    fn extend(&mut self, other: &AttributeValues) {
+        match (self, other) {
+            (AttributeValues::Float32(a), AttributeValues::Float32(b)) => a.extend_from_slice(b),
+            (AttributeValues::Float32x2(a), AttributeValues::Float32x2(b)) => a.extend_from_slice(b),
+            (AttributeValues::Float32x3(a), AttributeValues::Float32x3(b)) => a.extend_from_slice(b),
+            (AttributeValues::Float32x4(a), AttributeValues::Float32x4(b)) => a.extend_from_slice(b),
+            _ => panic!("Attributes with the same name must have the same format"),
+        }
+    }
+}
+
+// An extra vertex attribute. The id only has to be unique per name, it is
+// what Bevy uses to tell custom attributes apart.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attribute {
+    pub name: &'static str,
+    pub id: usize,
+    pub values: AttributeValues,
+}
+
+// An indexed triangle list, as produced by all of the meshers. Positions
+// are in the precision of the samples they came from; normals are always
+// single precision.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MeshData<T: Real = f32> {
+    pub positions: Vec<[T; 3]>,
+    pub normals: Vec<[f32; 3]>,
+    pub indices: Vec<u32>,
+    pub attributes: Vec<Attribute>,
+}
+
+impl<T: Real> MeshData<T> {
+    pub fn new(
+        positions: Vec<[T; 3]>,
+        normals: Vec<[f32; 3]>,
+        indices: Vec<u32>,
+    ) -> Self {
+        Self {
+            positions,
+            normals,
+            indices,
+            attributes: Vec::new(),
+        }
+    }
+
+    // Every three consecutive positions form a triangle.
+    pub fn unindexed(
+        positions: Vec<[T; 3]>,
+        normals: Vec<[f32; 3]>,
+    ) -> Self {
+        let indices = (0..positions.len() as u32).collect();
+
+        Self::new(positions, normals, indices)
+    }
+
+    pub fn with_attribute(mut self, attribute: Attribute) -> Self {
+        self.attributes.push(attribute);
+        self
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    // Appends `other`, offsetting its indices. Both meshes must carry the
+    // same extra attributes.
+    pub fn merge(&mut self, other: &MeshData<T>) {
+        let offset = self.positions.len() as u32;
+
+        assert_eq!(
+            self.attributes.len(), other.attributes.len(),
+            "Merged meshes must have the same attributes",
+        );
+
+        for attribute in self.attributes.iter_mut() {
+            let other_attribute = other.attributes.iter()
+                .find(|a| a.name == attribute.name)
+                .expect("Merged meshes must have the same attributes");
+
+            attribute.values.extend(&other_attribute.values);
+        }
+
+        self.positions.extend_from_slice(&other.positions);
+        self.normals.extend_from_slice(&other.normals);
+        self.indices.extend(other.indices.iter().map(|i| i + offset));
+    }
+
+    // Axis-aligned (min, max) corners of all positions, if there are any.
+    pub fn bounds(&self) -> Option<([T; 3], [T; 3])> {
+        let first = *self.positions.first()?;
+
+        let bounds = self.positions.iter().fold((first, first), |(min, max), &p| {
+            (std::array::from_fn(|axis| min[axis].min(p[axis])), std::array::from_fn(|axis| max[axis].max(p[axis])))
+        });
+
+        Some(bounds)
+    }
+
+    // Transforms positions and normals. Mirroring transforms also flip the
+    // winding so that triangles keep facing the same way as their normals.
+    // Positions are transformed in their own precision.
+    pub fn transform(&mut self, transform: Affine3A) {
+        let normal_matrix = transform.matrix3.inverse().transpose();
+
+        for position in self.positions.iter_mut() {
+            *position = real::transform_point(&transform, *position);
+        }
+
+        for normal in self.normals.iter_mut() {
+            *normal = (normal_matrix * Vec3::from(*normal)).normalize_or_zero().into();
+        }
+
+        if transform.matrix3.determinant() < 0.0 {
+            for triangle in self.indices.chunks_exact_mut(3) {
+                triangle.swap(1, 2);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "bevy")]
+impl From<MeshData> for bevy_render::mesh::Mesh {
+    fn from(mesh_data: MeshData) -> Self {
+        use bevy_render::{
+            mesh::{Indices, Mesh, MeshVertexAttribute, VertexAttributeValues},
+            render_resource::{PrimitiveTopology, VertexFormat},
+        };
+
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.set_indices(Some(Indices::U32(mesh_data.indices)));
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, mesh_data.positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, mesh_data.normals);
+
+        for attribute in mesh_data.attributes {
+            let (format, values) = match attribute.values {
+                AttributeValues::Float32(v) => (VertexFormat::Float32, VertexAttributeValues::Float32(v)),
+                AttributeValues::Float32x2(v) => (VertexFormat::Float32x2, VertexAttributeValues::Float32x2(v)),
+                AttributeValues::Float32x3(v) => (VertexFormat::Float32x3, VertexAttributeValues::Float32x3(v)),
+                AttributeValues::Float32x4(v) => (VertexFormat::Float32x4, VertexAttributeValues::Float32x4(v)),
+            };
+
+            mesh.insert_attribute(MeshVertexAttribute::new(attribute.name, attribute.id, format), values);
+        }
+
+        mesh
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt::Debug;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+// The floating point types that samples and positions can be stored in.
+// Single precision is the default everywhere; double precision keeps
+// vertices steady far away from the origin, such as in kilometre-scale
+// terrain.
+pub trait Real:
+    Copy
+    + Debug
+    + Default
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + Sum
+    + Send
+    + Sync
+    + 'static
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+
+    fn from_f32(value: f32) -> Self;
+
+    fn from_usize(value: usize) -> Self;
+
+    fn to_f32(self) -> f32;
+
+    fn to_f64(self) -> f64;
+
+    fn min(self, other: Self) -> Self;
+
+    fn max(self, other: Self) -> Self;
+
+    fn sqrt(self) -> Self;
+
+    // The closest values above and below, for rounding outwards.
+    fn next_up(self) -> Self;
+
+    fn next_down(self) -> Self;
+}
+
+impl Real for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const INFINITY: Self = f32::INFINITY;
+    const NEG_INFINITY: Self = f32::NEG_INFINITY;
+
+    fn from_f32(value: f32) -> Self {
+        value
+    }
+
+    fn from_usize(value: usize) -> Self {
+        value as f32
+    }
+
+    fn to_f32(self) -> f32 {
+        self
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn min(self, other: Self) -> Self {
+        f32::min(self, other)
+    }
+
+    fn max(self, other: Self) -> Self {
+        f32::max(self, other)
+    }
+
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+
+    fn next_up(self) -> Self {
+        f32::next_up(self)
+    }
+
+    fn next_down(self) -> Self {
+        f32::next_down(self)
+    }
+}
+
+impl Real for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const INFINITY: Self = f64::INFINITY;
+    const NEG_INFINITY: Self = f64::NEG_INFINITY;
+
+    fn from_f32(value: f32) -> Self {
+        value as f64
+    }
+
+    fn from_usize(value: usize) -> Self {
+        value as f64
+    }
+
+    fn to_f32(self) -> f32 {
+        self as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn min(self, other: Self) -> Self {
+        f64::min(self, other)
+    }
+
+    fn max(self, other: Self) -> Self {
+        f64::max(self, other)
+    }
+
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+
+    fn next_up(self) -> Self {
+        f64::next_up(self)
+    }
+
+    fn next_down(self) -> Self {
+        f64::next_down(self)
+    }
+}
+
+// Component-wise helpers for positions, which are kept as plain arrays so
+// that they work for either precision.
+pub(crate) fn add<T: Real>(a: [T; 3], b: [T; 3]) -> [T; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+pub(crate) fn sub<T: Real>(a: [T; 3], b: [T; 3]) -> [T; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+pub(crate) fn scale<T: Real>(a: [T; 3], factor: T) -> [T; 3] {
+    [a[0] * factor, a[1] * factor, a[2] * factor]
+}
+
+// Same as `Affine3A::transform_point3`, in the precision of the point.
+pub(crate) fn transform_point<T: Real>(transform: &glam::Affine3A, [x, y, z]: [T; 3]) -> [T; 3] {
+    let column = |column: glam::Vec3A| column.to_array().map(T::from_f32);
+    let matrix = transform.matrix3;
+
+    let rotated = add(add(scale(column(matrix.x_axis), x), scale(column(matrix.y_axis), y)), scale(column(matrix.z_axis), z));
+
+    add(rotated, column(transform.translation))
+}
+
+// The difference of two positions, which is small enough for single
+// precision even where the positions themselves are not.
+pub(crate) fn offset<T: Real>(to: [T; 3], from: [T; 3]) -> glam::Vec3 {
+    glam::Vec3::from(sub(to, from).map(T::to_f32))
+}
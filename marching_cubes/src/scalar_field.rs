@@ -0,0 +1,39 @@
+use crate::{Grid, Real};
+
+// A field the meshers extract surfaces from. Any `Fn(x, y, z) -> value`
+// is one. Fields that are cheaper to evaluate many points at a time, such
+// as vectorised noise, can override `sample_row` or `sample_block`, which
+// is how the meshers fill their grids of samples.
+pub trait ScalarField<T: Real = f32> {
+    fn sample(&self, x: T, y: T, z: T) -> T;
+
+    // Fills `values` with the samples at consecutive grid points of `grid`
+    // along the x axis, starting at grid point `start`.
+    fn sample_row(&self, grid: Grid<T>, [x, y, z]: [usize; 3], values: &mut [T]) {
+        for (i, value) in values.iter_mut().enumerate() {
+            let [wx, wy, wz] = grid.to_world(Grid::point([x + i, y, z]));
+            *value = self.sample(wx, wy, wz);
+        }
+    }
+
+    // Fills `values` with the samples at a block of `dimensions` grid
+    // points of `grid`, starting at grid point `start`, stored x-major like
+    // in a `VoxelGrid`.
+    fn sample_block(&self, grid: Grid<T>, [x, y, z]: [usize; 3], dimensions: [usize; 3], values: &mut [T]) {
+        for (row, values) in values.chunks_exact_mut(dimensions[0]).enumerate() {
+            let (row_y, row_z) = (row % dimensions[1], row / dimensions[1]);
+            self.sample_row(grid, [x, y + row_y, z + row_z], values);
+        }
+    }
+}
+
+impl<T: Real, F: Fn(T, T, T) -> T + ?Sized> ScalarField<T> for F {
+    fn sample(&self, x: T, y: T, z: T) -> T {
+        self(x, y, z)
+    }
+}
+
+// A field that can be sampled from several threads at once.
+pub trait SyncScalarField<T: Real = f32>: ScalarField<T> + Sync {}
+
+impl<T: Real, F: ScalarField<T> + Sync + ?Sized> SyncScalarField<T> for F {}
@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+
+use glam::Vec3;
+
+use crate::dual_contouring::field_gradient;
+use crate::marching_cubes::calculate_smooth_normals;
+use crate::{Grid, Isolevel, MeshData, ScalarField};
+
+// How much each neighbour pulls on a vertex.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Weights {
+    // All neighbours alike. Also evens out the triangle sizes, which
+    // spreads slivers out but lets vertices slide along the surface.
+    #[default]
+    Uniform,
+    // Half the sum of the cotangents of the angles opposite the edge, which
+    // only moves vertices across the surface. Negative sums, where the
+    // obtuse angle outweighs the acute one, are left out.
+    Cotangent,
+}
+
+// Every iteration moves each vertex `lambda` of the way towards the
+// weighted average of its neighbours. On its own that shrinks the mesh;
+// with a negative `mu` (Taubin, 1995) every step is followed by one of
+// `mu`, which pushes it back out. `mu` should be a little larger than
+// `lambda` in magnitude, such as 0.5 and -0.53.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Smoothing {
+    pub iterations: usize,
+    pub weights: Weights,
+    pub lambda: f32,
+    pub mu: Option<f32>,
+}
+
+impl Smoothing {
+    pub fn new(iterations: usize) -> Self {
+        Self { iterations, weights: Weights::Uniform, lambda: 0.5, mu: None }
+    }
+
+    pub fn with_weights(mut self, weights: Weights) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    pub fn with_lambda(mut self, lambda: f32) -> Self {
+        self.lambda = lambda;
+        self
+    }
+
+    pub fn with_mu(mut self, mu: f32) -> Self {
+        self.mu = Some(mu);
+        self
+    }
+}
+
+// Smooths the positions of an indexed mesh, keeping its triangles. Vertices
+// on the border of an open mesh stay where they are, so that chunks
+// smoothed on their own still meet. Normals, if the mesh has any, are
+// recomputed from the smoothed triangles.
+pub fn smooth(mesh: &MeshData, smoothing: Smoothing) -> MeshData {
+    let mut smoother = Smoother::new(mesh);
+
+    for _ in 0..smoothing.iterations {
+        smoother.step(smoothing.weights, smoothing.lambda);
+
+        if let Some(mu) = smoothing.mu {
+            smoother.step(smoothing.weights, mu);
+        }
+    }
+
+    let mut smoothed = mesh.clone();
+    smoothed.positions = smoother.positions.iter().map(|&p| p.into()).collect();
+
+    if !mesh.normals.is_empty() {
+        smoothed.normals = calculate_smooth_normals(&smoothed.positions, &smoothed.indices);
+    }
+
+    smoothed
+}
+
+// Same as `smooth`, but moves the vertices back onto the isosurface of
+// `scalar_field` along its gradient after every iteration, so that the
+// mesh only gets smoother without losing its shape. The gradient is taken
+// over a fraction of the spacing of `grid`, which should be the one the
+// mesh was made from. Normals come from the gradient as well.
+pub fn smooth_onto_field(
+    mesh: &MeshData,
+    smoothing: Smoothing,
+    grid: Grid,
+    scalar_field: &(impl ScalarField + ?Sized),
+    isolevel: Isolevel,
+) -> MeshData {
+    let mut smoother = Smoother::new(mesh);
+
+    for _ in 0..smoothing.iterations {
+        smoother.step(smoothing.weights, smoothing.lambda);
+
+        if let Some(mu) = smoothing.mu {
+            smoother.step(smoothing.weights, mu);
+        }
+
+        for (position, &fixed) in smoother.positions.iter_mut().zip(&smoother.border) {
+            if !fixed {
+                *position = project(grid, scalar_field, isolevel, *position);
+            }
+        }
+    }
+
+    let mut smoothed = mesh.clone();
+    smoothed.positions = smoother.positions.iter().map(|&p| p.into()).collect();
+
+    if !mesh.normals.is_empty() {
+        smoothed.normals = smoother.positions
+            .iter()
+            .map(|&p| {
+                let gradient = field_gradient(scalar_field, grid, grid.to_grid(p.into()));
+                Vec3::from(isolevel.orient(gradient.into())).normalize_or_zero().into()
+            })
+            .collect();
+    }
+
+    smoothed
+}
+
+// Newton steps along the gradient towards the isolevel. A step never goes
+// further than a cell, so that a vertex can't jump over to another part
+// of the surface.
+fn project(grid: Grid, scalar_field: &(impl ScalarField + ?Sized), isolevel: Isolevel, mut position: Vec3) -> Vec3 {
+    let max_step = Vec3::from(grid.spacing).max_element();
+
+    for _ in 0..8 {
+        let value = scalar_field.sample(position.x, position.y, position.z) - isolevel.level;
+
+        if value == 0.0 {
+            break;
+        }
+
+        let gradient = field_gradient(scalar_field, grid, grid.to_grid(position.into()));
+
+        if gradient.length_squared() == 0.0 {
+            break;
+        }
+
+        position -= (value * gradient / gradient.length_squared()).clamp_length_max(max_step);
+    }
+
+    position
+}
+
+struct Smoother {
+    positions: Vec<Vec3>,
+    triangles: Vec<[usize; 3]>,
+    // Other ends of the edges of every vertex.
+    neighbours: Vec<Vec<usize>>,
+    border: Vec<bool>,
+}
+
+impl Smoother {
+    fn new(mesh: &MeshData) -> Self {
+        let positions: Vec<Vec3> = mesh.positions.iter().map(|&p| p.into()).collect();
+        let triangles: Vec<[usize; 3]> = mesh.indices.chunks_exact(3).map(|t| [0, 1, 2].map(|k| t[k] as usize)).collect();
+
+        let mut edge_uses: HashMap<(usize, usize), usize> = HashMap::new();
+
+        for triangle in &triangles {
+            for k in 0..3 {
+                let (a, b) = (triangle[k], triangle[(k + 1) % 3]);
+                *edge_uses.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+            }
+        }
+
+        let mut neighbours = vec![Vec::new(); positions.len()];
+        let mut border = vec![false; positions.len()];
+
+        for (&(a, b), &uses) in &edge_uses {
+            neighbours[a].push(b);
+            neighbours[b].push(a);
+
+            if uses != 2 {
+                border[a] = true;
+                border[b] = true;
+            }
+        }
+
+        Self { positions, triangles, neighbours, border }
+    }
+
+    fn step(&mut self, weights: Weights, factor: f32) {
+        let edge_weights = match weights {
+            Weights::Uniform => HashMap::new(),
+            Weights::Cotangent => self.cotangent_weights(),
+        };
+
+        let moved: Vec<Vec3> = self.positions
+            .iter()
+            .enumerate()
+            .map(|(v, &position)| {
+                if self.border[v] || self.neighbours[v].is_empty() {
+                    return position;
+                }
+
+                let weight = |n: usize| match weights {
+                    Weights::Uniform => 1.0,
+                    Weights::Cotangent => edge_weights.get(&(v.min(n), v.max(n))).copied().unwrap_or(0.0),
+                };
+
+                let (sum, total) = self.neighbours[v].iter().fold((Vec3::ZERO, 0.0), |(sum, total), &n| {
+                    (sum + weight(n) * self.positions[n], total + weight(n))
+                });
+
+                // Only obtuse or degenerate triangles around, so nothing to
+                // pull towards.
+                if total <= 0.0 {
+                    return position;
+                }
+
+                position + factor * (sum / total - position)
+            })
+            .collect();
+
+        self.positions = moved;
+    }
+
+    fn cotangent_weights(&self) -> HashMap<(usize, usize), f32> {
+        let mut weights = HashMap::new();
+
+        for triangle in &self.triangles {
+            for k in 0..3 {
+                let (a, b, opposite) = (triangle[k], triangle[(k + 1) % 3], triangle[(k + 2) % 3]);
+                let (u, v) = (self.positions[a] - self.positions[opposite], self.positions[b] - self.positions[opposite]);
+                let sine = u.cross(v).length();
+                let cotangent = if sine > 0.0 { u.dot(v) / sine } else { 0.0 };
+
+                *weights.entry((a.min(b), a.max(b))).or_insert(0.0) += cotangent / 2.0;
+            }
+        }
+
+        // Weights grow without bound on slivers, which would otherwise swamp
+        // every other neighbour. Sums that cancel out to NaN count as none.
+        for weight in weights.values_mut() {
+            *weight = if *weight > 0.0 { weight.min(1e3) } else { 0.0 };
+        }
+
+        weights
+    }
+}
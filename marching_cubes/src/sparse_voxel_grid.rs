@@ -0,0 +1,235 @@
+use crate::voxel_grid::{Voxels, VoxelsMut};
+use crate::{Grid, Real, ScalarField};
+
+// Number of grid points along each side of the leaves of a
+// `SparseVoxelGrid`.
+pub const LEAF_SIZE: usize = 8;
+
+// Samples at the points of a grid, in an octree whose nodes hold a single
+// value wherever every sample below them is the same. Worlds that are
+// mostly solid or empty, or fields clamped to a band around the surface,
+// take a fraction of the memory of a `VoxelGrid`.
+pub struct SparseVoxelGrid<T: Real = f32> {
+    grid: Grid<T>,
+    dimensions: [usize; 3],
+    // Grid points along each side of the root: a power of two, and at
+    // least `LEAF_SIZE`. Nodes past the far borders of the grid are never
+    // read, and don't keep their parents from collapsing.
+    side: usize,
+    root: Node<T>,
+}
+
+enum Node<T> {
+    Uniform(T),
+    // `LEAF_SIZE`³ samples, stored x-major.
+    Leaf(Box<[T]>),
+    // Octants indexed by their x, y and z halves as bits 0, 1 and 2.
+    Branch(Box<[Node<T>; 8]>),
+}
+
+impl<T: Real> SparseVoxelGrid<T> {
+    // Every sample set to `value`.
+    pub fn new(grid: Grid<T>, value: T) -> Self {
+        let dimensions = grid.dimensions();
+        let side = dimensions.into_iter().max().unwrap().next_power_of_two().max(LEAF_SIZE);
+
+        Self { grid, dimensions, side, root: Node::Uniform(value) }
+    }
+
+    // Samples `scalar_field` at the world position of every grid point,
+    // like `VoxelGrid::from_scalar_field`, with one `sample_block` per leaf.
+    pub fn from_scalar_field(grid: Grid<T>, scalar_field: &(impl ScalarField<T> + ?Sized)) -> Self {
+        Self::from_blocks(grid, |start, dimensions, values| scalar_field.sample_block(grid, start, dimensions, values))
+    }
+
+    // A sparse copy of samples stored some other way.
+    pub fn from_voxels(voxels: &(impl Voxels<T> + ?Sized)) -> Self {
+        Self::from_blocks(voxels.grid(), |[x, y, z], [dx, dy, _], values| {
+            for (i, value) in values.iter_mut().enumerate() {
+                *value = voxels.read(x + i % dx, y + i / dx % dy, z + i / (dx * dy));
+            }
+        })
+    }
+
+    // Builds the tree from blocks of samples, filled like `sample_block`.
+    fn from_blocks(grid: Grid<T>, fill: impl Fn([usize; 3], [usize; 3], &mut [T])) -> Self {
+        let mut sparse = Self::new(grid, T::ZERO);
+        sparse.root = sparse.build([0; 3], sparse.side, &fill);
+        sparse
+    }
+
+    // Sets one sample. Uniform nodes on the way to it are split, and nodes
+    // left uniform by the change are merged back.
+    pub fn write(&mut self, x: usize, y: usize, z: usize, value: T) {
+        let point = [x, y, z];
+        assert!((0..3).all(|axis| point[axis] < self.dimensions[axis]), "{point:?} is outside of the grid");
+
+        let root = std::mem::replace(&mut self.root, Node::Uniform(T::ZERO));
+        self.root = self.write_node(root, [0; 3], self.side, point, value);
+    }
+
+    // Number of values held, counting one for every uniform node.
+    pub fn stored_samples(&self) -> usize {
+        fn count<T>(node: &Node<T>) -> usize {
+            match node {
+                Node::Uniform(_) => 1,
+                Node::Leaf(samples) => samples.len(),
+                Node::Branch(children) => children.iter().map(count).sum(),
+            }
+        }
+
+        count(&self.root)
+    }
+
+    // The node of the `side`³ grid points from `low`.
+    fn build(&self, low: [usize; 3], side: usize, fill: &impl Fn([usize; 3], [usize; 3], &mut [T])) -> Node<T> {
+        if !self.holds_points(low) {
+            return Node::Uniform(T::ZERO);
+        }
+
+        if side == LEAF_SIZE {
+            // Only the part of the leaf within the grid is sampled, and
+            // then spread out over the rows of the leaf.
+            let dimensions: [usize; 3] = std::array::from_fn(|axis| (self.dimensions[axis] - low[axis]).min(LEAF_SIZE));
+
+            let mut block = vec![T::ZERO; dimensions[0] * dimensions[1] * dimensions[2]];
+            fill(low, dimensions, &mut block);
+
+            let mut samples = vec![T::ZERO; LEAF_SIZE.pow(3)].into_boxed_slice();
+
+            for (row, values) in block.chunks_exact(dimensions[0]).enumerate() {
+                let start = (row % dimensions[1] + row / dimensions[1] * LEAF_SIZE) * LEAF_SIZE;
+                samples[start..start + dimensions[0]].copy_from_slice(values);
+            }
+
+            return self.leaf(low, samples);
+        }
+
+        let half = side / 2;
+        let children = std::array::from_fn(|octant| self.build(octant_low(low, half, octant), half, fill));
+
+        self.branch(low, half, Box::new(children))
+    }
+
+    fn write_node(&self, node: Node<T>, low: [usize; 3], side: usize, point: [usize; 3], value: T) -> Node<T> {
+        if let Node::Uniform(uniform) = node {
+            if uniform == value {
+                return node;
+            }
+        }
+
+        if side == LEAF_SIZE {
+            let mut samples = match node {
+                Node::Uniform(uniform) => vec![uniform; LEAF_SIZE.pow(3)].into_boxed_slice(),
+                Node::Leaf(samples) => samples,
+                Node::Branch(_) => unreachable!("branches end in leaves"),
+            };
+
+            samples[leaf_index(point)] = value;
+
+            return self.leaf(low, samples);
+        }
+
+        let mut children = match node {
+            Node::Uniform(uniform) => Box::new(std::array::from_fn(|_| Node::Uniform(uniform))),
+            Node::Branch(children) => children,
+            Node::Leaf(_) => unreachable!("leaves are at the bottom"),
+        };
+
+        let half = side / 2;
+        let octant = octant_of(point, half);
+
+        let child = std::mem::replace(&mut children[octant], Node::Uniform(T::ZERO));
+        children[octant] = self.write_node(child, octant_low(low, half, octant), half, point, value);
+
+        self.branch(low, half, children)
+    }
+
+    // A leaf, or a single value if its samples inside the grid are all the
+    // same.
+    fn leaf(&self, low: [usize; 3], samples: Box<[T]>) -> Node<T> {
+        let mut inside = (0..samples.len()).filter(|&i| self.holds_points(leaf_point(low, i)));
+        let first = samples[inside.next().unwrap()];
+
+        if inside.all(|i| samples[i] == first) {
+            Node::Uniform(first)
+        } else {
+            Node::Leaf(samples)
+        }
+    }
+
+    // A branch, or a single value if its octants inside the grid are all
+    // uniform with the same value.
+    fn branch(&self, low: [usize; 3], half: usize, children: Box<[Node<T>; 8]>) -> Node<T> {
+        let mut values = (0..8)
+            .filter(|&octant| self.holds_points(octant_low(low, half, octant)))
+            .map(|octant| match children[octant] {
+                Node::Uniform(value) => Some(value),
+                _ => None,
+            });
+
+        let first = values.next().unwrap();
+
+        match first {
+            Some(first) if values.all(|value| value == Some(first)) => Node::Uniform(first),
+            _ => Node::Branch(children),
+        }
+    }
+
+    // Whether a node from `low` holds any grid point.
+    fn holds_points(&self, low: [usize; 3]) -> bool {
+        (0..3).all(|axis| low[axis] < self.dimensions[axis])
+    }
+}
+
+impl<T: Real> Voxels<T> for SparseVoxelGrid<T> {
+    fn grid(&self) -> Grid<T> {
+        self.grid
+    }
+
+    fn read(&self, x: usize, y: usize, z: usize) -> T {
+        let point = [x, y, z];
+        let mut node = &self.root;
+        let mut half = self.side / 2;
+
+        loop {
+            match node {
+                Node::Uniform(value) => return *value,
+                Node::Leaf(samples) => return samples[leaf_index(point)],
+                Node::Branch(children) => {
+                    node = &children[octant_of(point, half)];
+                    half /= 2;
+                }
+            }
+        }
+    }
+
+    fn dimensions(&self) -> [usize; 3] {
+        self.dimensions
+    }
+}
+
+impl<T: Real> VoxelsMut<T> for SparseVoxelGrid<T> {
+    fn write(&mut self, x: usize, y: usize, z: usize, value: T) {
+        SparseVoxelGrid::write(self, x, y, z, value)
+    }
+}
+
+// Node sides are powers of two from the origin, so the bits of a
+// coordinate pick the octants on the way down.
+fn octant_of(point: [usize; 3], half: usize) -> usize {
+    (0..3).fold(0, |octant, axis| octant | ((point[axis] & half != 0) as usize) << axis)
+}
+
+fn octant_low(low: [usize; 3], half: usize, octant: usize) -> [usize; 3] {
+    std::array::from_fn(|axis| low[axis] + (octant >> axis & 1) * half)
+}
+
+fn leaf_index([x, y, z]: [usize; 3]) -> usize {
+    let mask = LEAF_SIZE - 1;
+    (x & mask) + (y & mask) * LEAF_SIZE + (z & mask) * LEAF_SIZE * LEAF_SIZE
+}
+
+fn leaf_point(low: [usize; 3], index: usize) -> [usize; 3] {
+    [low[0] + index % LEAF_SIZE, low[1] + index / LEAF_SIZE % LEAF_SIZE, low[2] + index / LEAF_SIZE / LEAF_SIZE]
+}
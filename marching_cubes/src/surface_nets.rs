@@ -0,0 +1,385 @@
+use crate::voxel_grid::{ActiveBlocks, Padded, Voxels};
+use crate::{Grid, Isolevel, MeshData, Real, ScalarField, VoxelGrid};
+
+pub(crate) type DiscreteScalarField<'a, T = f32> = dyn Fn(usize, usize, usize) -> T + 'a;
+
+// Main algorithm driver.
+pub fn surface_net<T: Real>(
+    grid: Grid<T>,
+    scalar_field: &(impl ScalarField<T> + ?Sized),
+    isolevel: Isolevel<T>,
+) -> MeshData<T> {
+    let voxel_grid = VoxelGrid::from_scalar_field(grid, scalar_field);
+
+    surface_net_voxels(&voxel_grid, isolevel)
+}
+
+// Same as `surface_net`, over samples that are already stored, such as a
+// `SparseVoxelGrid`. They are read in place rather than copied.
+pub fn surface_net_voxels<T: Real>(
+    voxels: &(impl Voxels<T> + ?Sized),
+    isolevel: Isolevel<T>,
+) -> MeshData<T> {
+    let grid = voxels.grid();
+    let active_blocks = voxels.min_max_pyramid().active_blocks(isolevel);
+
+    let discrete_scalar_field = &|x, y, z| voxels.read(x, y, z);
+
+    contour_cells(grid, discrete_scalar_field, &active_blocks, isolevel, |coord| {
+        find_center(discrete_scalar_field, grid, isolevel, coord)
+    })
+}
+
+// Same as `surface_net`, but counts everything beyond the grid as outside,
+// so that the surface is capped where it leaves the grid and every piece
+// of it is closed.
+pub fn surface_net_capped<T: Real>(
+    grid: Grid<T>,
+    scalar_field: &(impl ScalarField<T> + ?Sized),
+    isolevel: Isolevel<T>,
+) -> MeshData<T> {
+    let voxel_grid = VoxelGrid::from_scalar_field(grid, scalar_field);
+
+    surface_net_voxels_capped(&voxel_grid, isolevel)
+}
+
+// Same as `surface_net_capped`, over samples that are already stored.
+// The samples are padded with a layer of outside points, and the vertices
+// of the cells in that layer are pressed flat onto the faces of the grid.
+pub fn surface_net_voxels_capped<T: Real>(
+    voxels: &(impl Voxels<T> + ?Sized),
+    isolevel: Isolevel<T>,
+) -> MeshData<T> {
+    let padded = Padded::new(voxels, isolevel);
+    let grid = padded.grid();
+    let active_blocks = padded.min_max_pyramid().active_blocks(isolevel);
+
+    let inner = voxels.grid();
+    let (low, high) = (inner.origin, inner.to_world(Grid::point(inner.size)));
+
+    let discrete_scalar_field = &|x, y, z| padded.read(x, y, z);
+
+    contour_cells(grid, discrete_scalar_field, &active_blocks, isolevel, |coord| {
+        let (center, normal) = find_center(discrete_scalar_field, grid, isolevel, coord)?;
+        let center = [0, 1, 2].map(|axis| center[axis].max(low[axis]).min(high[axis]));
+        Some((center, normal))
+    })
+}
+
+// Places a vertex (and its normal) in every cell the surface passes
+// through with `place_vertex`, and joins them with quads across every edge
+// the surface crosses. Cells outside of `active_blocks` are skipped.
+pub(crate) fn contour_cells<T: Real>(
+    grid: Grid<T>,
+    discrete_scalar_field: &DiscreteScalarField<T>,
+    active_blocks: &ActiveBlocks,
+    isolevel: Isolevel<T>,
+    mut place_vertex: impl FnMut((usize, usize, usize)) -> Option<([T; 3], [f32; 3])>,
+) -> MeshData<T> {
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut indices = Vec::new();
+    let mut grid_to_index = CellCache::new(grid.size);
+
+    // The cells of a layer that lie in active blocks, row by row.
+    let layer = |z| {
+        (0..grid.size[1]).flat_map(move |y| active_blocks.row(y, z, grid.size[0]).map(move |x| (x, y, z)))
+    };
+
+    for z in 0..grid.size[2] {
+        grid_to_index.advance(z);
+
+        // Find all vertex positions in this layer. Addtionally, remember
+        // the index of each grid position.
+        for (x, y, z) in layer(z) {
+            if let Some((center, normal)) = place_vertex((x, y, z)) {
+                grid_to_index.insert((x, y, z), positions.len());
+                positions.push(center);
+                normals.push(normal);
+            }
+        }
+
+        // Quads only reach back into the previous layer, so they can be
+        // made as soon as this one is done.
+        let mut quads = Quads {
+            discrete_scalar_field,
+            isolevel,
+            grid_to_index: &grid_to_index,
+            positions: &positions,
+            indices: &mut indices,
+        };
+
+        quads.make_layer_triangles(layer(z));
+    }
+
+    MeshData::new(positions, normals, indices)
+}
+
+// Vertex indices of the cells in the current and the previous layer.
+struct CellCache {
+    size: [usize; 3],
+    layers: [Vec<u32>; 2],
+}
+
+impl CellCache {
+    const EMPTY: u32 = u32::MAX;
+
+    fn new(size: [usize; 3]) -> Self {
+        let layer_len = size[0] * size[1];
+
+        Self {
+            size,
+            layers: [vec![Self::EMPTY; layer_len], vec![Self::EMPTY; layer_len]],
+        }
+    }
+
+    // Clears the slot of layer `z`, which still holds layer `z - 2`.
+    fn advance(&mut self, z: usize) {
+        self.layers[z % 2].fill(Self::EMPTY);
+    }
+
+    fn insert(&mut self, (x, y, z): (usize, usize, usize), index: usize) {
+        self.layers[z % 2][x + y * self.size[0]] = index as u32;
+    }
+
+    fn get(&self, (x, y, z): (usize, usize, usize)) -> Option<usize> {
+        match self.layers[z % 2][x + y * self.size[0]] {
+            Self::EMPTY => None,
+            index => Some(index as usize),
+        }
+    }
+}
+
+pub(crate) const OFFSETS: [(usize, usize); 12] = [
+    (0b000, 0b001),
+    (0b000, 0b010),
+    (0b000, 0b100),
+    (0b001, 0b011),
+    (0b001, 0b101),
+    (0b010, 0b011),
+    (0b010, 0b110),
+    (0b011, 0b111),
+    (0b100, 0b101),
+    (0b100, 0b110),
+    (0b101, 0b111),
+    (0b110, 0b111),
+];
+
+fn find_center<T: Real>(
+    discrete_scalar_field: &DiscreteScalarField<T>,
+    grid: Grid<T>,
+    isolevel: Isolevel<T>,
+    coord: (usize, usize, usize),
+) -> Option<([T; 3], [f32; 3])> {
+    let mut values = [T::ZERO; 8];
+    for (x, value) in values.iter_mut().enumerate() {
+        *value = discrete_scalar_field(
+            coord.0 + ((x >> 2) & 1),
+            coord.1 + ((x >> 1) & 1),
+            coord.2 + (x & 1),
+        );
+    }
+    let edges = OFFSETS.iter().filter_map(|&(offset1, offset2)| {
+        find_edge(isolevel, offset1, offset2, values[offset1], values[offset2])
+    });
+    let mut count = 0;
+    let mut sum = [T::ZERO; 3];
+    for edge in edges {
+        count += 1;
+        sum[0] += edge[0];
+        sum[1] += edge[1];
+        sum[2] += edge[2];
+    }
+    if count == 0 {
+        None
+    } else {
+        let normal_x = (values[0b100] + values[0b101] + values[0b110] + values[0b111]) - (values[0b000] + values[0b001] + values[0b010] + values[0b011]);
+        let normal_y = (values[0b010] + values[0b011] + values[0b110] + values[0b111]) - (values[0b000] + values[0b001] + values[0b100] + values[0b101]);
+        let normal_z = (values[0b001] + values[0b011] + values[0b101] + values[0b111]) - (values[0b000] + values[0b010] + values[0b100] + values[0b110]);
+        // Differences are taken in grid units, scale them to world units.
+        let (normal_x, normal_y, normal_z) = (
+            normal_x / grid.spacing[0],
+            normal_y / grid.spacing[1],
+            normal_z / grid.spacing[2],
+        );
+        let [normal_x, normal_y, normal_z] = isolevel.orient([normal_x, normal_y, normal_z]).map(T::to_f32);
+        let normal_len = (normal_x * normal_x + normal_y * normal_y + normal_z * normal_z).sqrt();
+        Some((
+            grid.to_world([
+                sum[0] / T::from_usize(count) + T::from_usize(coord.0),
+                sum[1] / T::from_usize(count) + T::from_usize(coord.1),
+                sum[2] / T::from_usize(count) + T::from_usize(coord.2),
+            ]),
+            [
+                normal_x / normal_len,
+                normal_y / normal_len,
+                normal_z / normal_len,
+            ],
+        ))
+    }
+}
+
+// Given two points, A and B, find the point between them where the SDF
+// crosses the isolevel. (This might not exist).
+// A and B are specified via A=coord+offset1 and B=coord+offset2, because code
+// is weird.
+pub(crate) fn find_edge<T: Real>(isolevel: Isolevel<T>, offset1: usize, offset2: usize, value1: T, value2: T) -> Option<[T; 3]> {
+    if isolevel.is_inside(value1) == isolevel.is_inside(value2) {
+        return None;
+    }
+    let interp = isolevel.crossing(value1, value2);
+    let bit = |offset: usize, shift: usize| T::from_usize((offset >> shift) & 1);
+    let point = [
+        bit(offset1, 2) * (T::ONE - interp) + bit(offset2, 2) * interp,
+        bit(offset1, 1) * (T::ONE - interp) + bit(offset2, 1) * interp,
+        bit(offset1, 0) * (T::ONE - interp) + bit(offset2, 0) * interp,
+    ];
+    Some(point)
+}
+
+// What the quads of a layer are made from, and where their triangles go.
+struct Quads<'a, T: Real> {
+    discrete_scalar_field: &'a DiscreteScalarField<'a, T>,
+    isolevel: Isolevel<T>,
+    grid_to_index: &'a CellCache,
+    positions: &'a [[T; 3]],
+    indices: &'a mut Vec<u32>,
+}
+
+impl<T: Real> Quads<'_, T> {
+    // For every edge that crosses the boundary, make a quad between the
+    // "centers" of the four cubes touching that boundary. (Well, really, two
+    // triangles) The "centers" are actually the vertex positions, found earlier.
+    // (Also, make sure the triangles are facing the right way)
+    // There's some hellish off-by-one conditions and whatnot that make this code
+    // really gross.
+    fn make_layer_triangles(&mut self, cells: impl Iterator<Item = (usize, usize, usize)>) {
+        // Both ends of the edges from (x, y, z) are corners of cell (x, y, z),
+        // so the edges of cells that were skipped don't cross the isolevel.
+        for (x, y, z) in cells {
+            // TODO: Cache discrete_scalar_field(coord), it's called three times here.
+            // Do edges parallel with the X axis
+            if y != 0 && z != 0 {
+                self.make_triangle((x, y, z), (1, 0, 0), (0, 1, 0), (0, 0, 1));
+            }
+            // Do edges parallel with the Y axis
+            if x != 0 && z != 0 {
+                self.make_triangle((x, y, z), (0, 1, 0), (0, 0, 1), (1, 0, 0));
+            }
+            // Do edges parallel with the Z axis
+            if x != 0 && y != 0 {
+                self.make_triangle((x, y, z), (0, 0, 1), (1, 0, 0), (0, 1, 0));
+            }
+        }
+    }
+
+    fn make_triangle(
+        &mut self,
+        coord: (usize, usize, usize),
+        offset: (usize, usize, usize),
+        axis1: (usize, usize, usize),
+        axis2: (usize, usize, usize),
+    ) {
+        let face_result = is_face(self.discrete_scalar_field, self.isolevel, coord, offset);
+        if let FaceResult::NoFace = face_result {
+            return;
+        }
+        // The triangle points, viewed face-front, look like this:
+        // v1 v3
+        // v2 v4
+        let v1 = self.grid_to_index.get((coord.0, coord.1, coord.2)).unwrap();
+        let v2 = self.grid_to_index
+            .get((coord.0 - axis1.0, coord.1 - axis1.1, coord.2 - axis1.2))
+            .unwrap();
+        let v3 = self.grid_to_index
+            .get((coord.0 - axis2.0, coord.1 - axis2.1, coord.2 - axis2.2))
+            .unwrap();
+        let v4 = self.grid_to_index
+            .get((
+                coord.0 - axis1.0 - axis2.0,
+                coord.1 - axis1.1 - axis2.1,
+                coord.2 - axis1.2 - axis2.2,
+            )).unwrap();
+        // optional addition to algorithm: split quad to triangles in a certain way
+        let p1 = self.positions[v1];
+        let p2 = self.positions[v2];
+        let p3 = self.positions[v3];
+        let p4 = self.positions[v4];
+        fn dist<T: Real>(a: [T; 3], b: [T; 3]) -> T {
+            let d = [a[0] - b[0], a[1] - b[1], a[2] - b[2]];
+            d[0] * d[0] + d[1] * d[1] + d[2] * d[2]
+        }
+        let d14 = dist(p1, p4);
+        let d23 = dist(p2, p3);
+        // Split the quad along the shorter axis, rather than the longer one.
+        if d14 < d23 {
+            match face_result {
+                FaceResult::NoFace => (),
+                FaceResult::FacePositive => {
+                    self.indices.push(v1 as u32);
+                    self.indices.push(v4 as u32);
+                    self.indices.push(v2 as u32);
+
+                    self.indices.push(v1 as u32);
+                    self.indices.push(v3 as u32);
+                    self.indices.push(v4 as u32);
+                }
+                FaceResult::FaceNegative => {
+                    self.indices.push(v1 as u32);
+                    self.indices.push(v2 as u32);
+                    self.indices.push(v4 as u32);
+
+                    self.indices.push(v1 as u32);
+                    self.indices.push(v4 as u32);
+                    self.indices.push(v3 as u32);
+                }
+            }
+        } else {
+            match face_result {
+                FaceResult::NoFace => (),
+                FaceResult::FacePositive => {
+                    self.indices.push(v2 as u32);
+                    self.indices.push(v3 as u32);
+                    self.indices.push(v4 as u32);
+
+                    self.indices.push(v2 as u32);
+                    self.indices.push(v1 as u32);
+                    self.indices.push(v3 as u32);
+                }
+                FaceResult::FaceNegative => {
+                    self.indices.push(v2 as u32);
+                    self.indices.push(v4 as u32);
+                    self.indices.push(v3 as u32);
+
+                    self.indices.push(v2 as u32);
+                    self.indices.push(v3 as u32);
+                    self.indices.push(v1 as u32);
+                }
+            }
+        }
+    }
+}
+
+enum FaceResult {
+    NoFace,
+    FacePositive,
+    FaceNegative,
+}
+
+// Determine if the SDF crosses the isolevel between coord and (coord+offset)
+fn is_face<T: Real>(
+    discrete_scalar_field: &DiscreteScalarField<T>,
+    isolevel: Isolevel<T>,
+    coord: (usize, usize, usize),
+    offset: (usize, usize, usize),
+) -> FaceResult {
+    let other = (coord.0 + offset.0, coord.1 + offset.1, coord.2 + offset.2);
+    match (
+        isolevel.is_inside(discrete_scalar_field(coord.0, coord.1, coord.2)),
+        isolevel.is_inside(discrete_scalar_field(other.0, other.1, other.2)),
+    ) {
+        (true, false) => FaceResult::FacePositive,
+        (false, true) => FaceResult::FaceNegative,
+        _ => FaceResult::NoFace,
+    }
+}
\ No newline at end of file
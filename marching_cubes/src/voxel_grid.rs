@@ -0,0 +1,444 @@
+use std::thread;
+
+use crate::{Isolevel, Real, ScalarField, SyncScalarField};
+
+// Placement of a block of cubes in world space. `size` counts cubes, so
+// the grid has one more point than cubes along each axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Grid<T: Real = f32> {
+    pub size: [usize; 3],
+    pub origin: [T; 3],
+    pub spacing: [T; 3],
+}
+
+impl<T: Real> Grid<T> {
+    pub fn new(size: [usize; 3]) -> Self {
+        Self {
+            size,
+            origin: [T::ZERO; 3],
+            spacing: [T::ONE; 3],
+        }
+    }
+
+    pub fn cube(resolution: usize) -> Self {
+        Self::new([resolution; 3])
+    }
+
+    pub fn with_origin(mut self, origin: [T; 3]) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    pub fn with_spacing(mut self, spacing: [T; 3]) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    // Number of grid points along each axis.
+    pub fn dimensions(&self) -> [usize; 3] {
+        [self.size[0] + 1, self.size[1] + 1, self.size[2] + 1]
+    }
+
+    // Maps a (possibly fractional) grid coordinate to world space.
+    pub fn to_world(&self, [x, y, z]: [T; 3]) -> [T; 3] {
+        [
+            self.origin[0] + x * self.spacing[0],
+            self.origin[1] + y * self.spacing[1],
+            self.origin[2] + z * self.spacing[2],
+        ]
+    }
+
+    // Maps a world position back to a (possibly fractional) grid coordinate.
+    pub fn to_grid(&self, [x, y, z]: [T; 3]) -> [T; 3] {
+        [
+            (x - self.origin[0]) / self.spacing[0],
+            (y - self.origin[1]) / self.spacing[1],
+            (z - self.origin[2]) / self.spacing[2],
+        ]
+    }
+
+    // The grid coordinate of a grid point.
+    pub(crate) fn point(point: [usize; 3]) -> [T; 3] {
+        point.map(T::from_usize)
+    }
+}
+
+// Samples of a scalar field at the points of a grid, stored x-major.
+pub struct VoxelGrid<T: Real = f32> {
+    data: Vec<T>,
+    grid: Grid<T>,
+    dimensions: [usize; 3],
+}
+
+impl<T: Real> VoxelGrid<T> {
+    pub fn new(grid: Grid<T>) -> Self {
+        let dimensions = grid.dimensions();
+
+        Self {
+            data: Vec::with_capacity(dimensions[0] * dimensions[1] * dimensions[2]),
+            grid,
+            dimensions,
+        }
+    }
+
+    // Samples `scalar_field` at the world position of every grid point.
+    pub fn from_scalar_field(grid: Grid<T>, scalar_field: &(impl ScalarField<T> + ?Sized)) -> Self {
+        Self::from_scalar_field_at(grid, scalar_field, grid, [0; 3])
+    }
+
+    // Samples `scalar_field` at the points of `sampled` from grid point
+    // `start` on, which must have the same spacing as `grid`. Neighbouring
+    // grids sampled this way from one larger grid agree where they meet.
+    pub(crate) fn from_scalar_field_at(
+        grid: Grid<T>,
+        scalar_field: &(impl ScalarField<T> + ?Sized),
+        sampled: Grid<T>,
+        start: [usize; 3],
+    ) -> Self {
+        let mut voxel_grid = Self::new(grid);
+        let dimensions = voxel_grid.dimensions;
+
+        voxel_grid.data.resize(dimensions.iter().product(), T::ZERO);
+        scalar_field.sample_block(sampled, start, dimensions, &mut voxel_grid.data);
+
+        voxel_grid
+    }
+
+    // Same as `from_scalar_field`, but samples groups of z-layers on
+    // `threads` threads at once.
+    pub fn from_scalar_field_parallel(
+        grid: Grid<T>,
+        scalar_field: &(impl SyncScalarField<T> + ?Sized),
+        threads: usize,
+    ) -> Self {
+        let mut voxel_grid = Self::new(grid);
+        let [size_x, size_y, size_z] = voxel_grid.dimensions;
+
+        let layer_len = size_x * size_y;
+        let layers_per_thread = size_z.div_ceil(threads.max(1)).max(1);
+
+        voxel_grid.data.resize(layer_len * size_z, T::ZERO);
+
+        thread::scope(|scope| {
+            for (i, chunk) in voxel_grid.data.chunks_mut(layer_len * layers_per_thread).enumerate() {
+                scope.spawn(move || {
+                    let layers = [size_x, size_y, chunk.len() / layer_len];
+                    scalar_field.sample_block(grid, [0, 0, i * layers_per_thread], layers, chunk);
+                });
+            }
+        });
+
+        voxel_grid
+    }
+
+    pub fn grid(&self) -> Grid<T> {
+        self.grid
+    }
+
+    pub fn dimensions(&self) -> [usize; 3] {
+        self.dimensions
+    }
+
+    pub fn read(&self, x: usize, y: usize, z: usize) -> T {
+        self.data[x + y * self.dimensions[0] + z * self.dimensions[0] * self.dimensions[1]]
+    }
+
+    pub fn write(&mut self, x: usize, y: usize, z: usize, value: T) {
+        self.data[x + y * self.dimensions[0] + z * self.dimensions[0] * self.dimensions[1]] = value;
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.data.push(value);
+    }
+}
+
+// Read access to the samples at the points of a grid, however they are
+// stored. The meshers that take a closure sample it into a `VoxelGrid`
+// first, the ones that take `Voxels` read them where they are.
+pub trait Voxels<T: Real = f32> {
+    fn grid(&self) -> Grid<T>;
+
+    fn read(&self, x: usize, y: usize, z: usize) -> T;
+
+    // Number of grid points along each axis.
+    fn dimensions(&self) -> [usize; 3] {
+        self.grid().dimensions()
+    }
+
+    // Smallest and largest samples of blocks of cubes, to skip the ones
+    // the surface can't pass through.
+    fn min_max_pyramid(&self) -> MinMaxPyramid<T> {
+        MinMaxPyramid::new(self)
+    }
+
+    // Central differences of the samples `epsilon` grid points to either
+    // side of a grid point, in world units. They become one-sided where
+    // that would reach past the border of the grid.
+    fn gradient(&self, x: usize, y: usize, z: usize, epsilon: usize) -> [T; 3] {
+        let point = [x, y, z];
+        let epsilon = epsilon.max(1);
+        let (dimensions, spacing) = (self.dimensions(), self.grid().spacing);
+
+        std::array::from_fn(|axis| {
+            let (mut low, mut high) = (point, point);
+            low[axis] = point[axis].saturating_sub(epsilon);
+            high[axis] = (point[axis] + epsilon).min(dimensions[axis] - 1);
+
+            let distance = T::from_usize(high[axis] - low[axis]) * spacing[axis];
+
+            (self.read(high[0], high[1], high[2]) - self.read(low[0], low[1], low[2])) / distance
+        })
+    }
+
+    // The gradients of the grid points around a world position, blended
+    // trilinearly. Along an edge of the grid, this is a linear blend of the
+    // gradients at its ends.
+    fn interpolated_gradient(&self, position: [T; 3], epsilon: usize) -> [T; 3] {
+        let coord = self.grid().to_grid(position);
+        let dimensions = self.dimensions();
+
+        // The cube holding the position, and where in it the position is.
+        let mut cube = [0; 3];
+        let mut fraction = [T::ZERO; 3];
+
+        for axis in 0..3 {
+            let last = dimensions[axis].saturating_sub(2);
+            cube[axis] = (coord[axis].max(T::ZERO).to_f32() as usize).min(last);
+            fraction[axis] = (coord[axis] - T::from_usize(cube[axis])).max(T::ZERO).min(T::ONE);
+        }
+
+        let mut gradient = [T::ZERO; 3];
+
+        for corner in 0..8 {
+            let offset = [corner & 1, corner >> 1 & 1, corner >> 2 & 1];
+            let [x, y, z] = std::array::from_fn(|axis| (cube[axis] + offset[axis]).min(dimensions[axis] - 1));
+
+            let weight = (0..3)
+                .map(|axis| if offset[axis] == 1 { fraction[axis] } else { T::ONE - fraction[axis] })
+                .fold(T::ONE, |product, factor| product * factor);
+
+            for (sum, value) in gradient.iter_mut().zip(self.gradient(x, y, z, epsilon)) {
+                *sum += weight * value;
+            }
+        }
+
+        gradient
+    }
+}
+
+// Voxels whose samples can be changed in place.
+pub trait VoxelsMut<T: Real = f32>: Voxels<T> {
+    fn write(&mut self, x: usize, y: usize, z: usize, value: T);
+}
+
+impl<T: Real> Voxels<T> for VoxelGrid<T> {
+    fn grid(&self) -> Grid<T> {
+        self.grid
+    }
+
+    fn read(&self, x: usize, y: usize, z: usize) -> T {
+        VoxelGrid::read(self, x, y, z)
+    }
+
+    fn dimensions(&self) -> [usize; 3] {
+        self.dimensions
+    }
+}
+
+impl<T: Real> VoxelsMut<T> for VoxelGrid<T> {
+    fn write(&mut self, x: usize, y: usize, z: usize, value: T) {
+        VoxelGrid::write(self, x, y, z, value)
+    }
+}
+
+// Other voxels with an extra layer of grid points all around, which are
+// outside of the surface. Meshing them closes every surface that leaves
+// the grid.
+pub(crate) struct Padded<'a, V: ?Sized, T = f32> {
+    voxels: &'a V,
+    outside: T,
+}
+
+impl<'a, T: Real, V: Voxels<T> + ?Sized> Padded<'a, V, T> {
+    pub fn new(voxels: &'a V, isolevel: Isolevel<T>) -> Self {
+        Self { voxels, outside: isolevel.outside() }
+    }
+
+    // Whether a grid point of the padded grid is in the padding.
+    pub fn is_padding(&self, point: [usize; 3]) -> bool {
+        let dimensions = self.voxels.dimensions();
+        (0..3).any(|axis| point[axis] == 0 || point[axis] > dimensions[axis])
+    }
+}
+
+impl<T: Real, V: Voxels<T> + ?Sized> Voxels<T> for Padded<'_, V, T> {
+    fn grid(&self) -> Grid<T> {
+        let grid = self.voxels.grid();
+
+        Grid::new(grid.size.map(|size| size + 2))
+            .with_origin(grid.to_world([-T::ONE; 3]))
+            .with_spacing(grid.spacing)
+    }
+
+    fn read(&self, x: usize, y: usize, z: usize) -> T {
+        if self.is_padding([x, y, z]) {
+            self.outside
+        } else {
+            self.voxels.read(x - 1, y - 1, z - 1)
+        }
+    }
+}
+
+// Number of cubes along each side of the smallest blocks of a
+// `MinMaxPyramid`.
+pub const BLOCK_SIZE: usize = 4;
+
+// The smallest and largest sample in blocks of `BLOCK_SIZE` cubes, then in
+// blocks of 2x2x2 of those, and so on up to a single block covering the
+// whole grid. Blocks along the far borders of the grid may be smaller.
+pub struct MinMaxPyramid<T: Real = f32> {
+    levels: Vec<MinMaxLevel<T>>,
+}
+
+struct MinMaxLevel<T> {
+    blocks: [usize; 3],
+    min: Vec<T>,
+    max: Vec<T>,
+}
+
+impl<T: Real> MinMaxLevel<T> {
+    fn from_fn(blocks: [usize; 3], range: impl Fn([usize; 3]) -> (T, T)) -> Self {
+        let len = blocks[0] * blocks[1] * blocks[2];
+        let (min, max) = (0..len)
+            .map(|index| range([index % blocks[0], index / blocks[0] % blocks[1], index / blocks[0] / blocks[1]]))
+            .unzip();
+
+        Self { blocks, min, max }
+    }
+
+    fn index(&self, [x, y, z]: [usize; 3]) -> usize {
+        x + y * self.blocks[0] + z * self.blocks[0] * self.blocks[1]
+    }
+}
+
+impl<T: Real> MinMaxPyramid<T> {
+    pub fn new(voxels: &(impl Voxels<T> + ?Sized)) -> Self {
+        let size = voxels.grid().size;
+        let blocks = size.map(|size| size.div_ceil(BLOCK_SIZE));
+
+        // A block covers the grid points of its cubes, including the ones
+        // on its far faces, which it shares with the next block.
+        let mut top = MinMaxLevel::from_fn(blocks, |[x, y, z]| {
+            let mut range = (T::INFINITY, T::NEG_INFINITY);
+
+            for pz in z * BLOCK_SIZE..=((z + 1) * BLOCK_SIZE).min(size[2]) {
+                for py in y * BLOCK_SIZE..=((y + 1) * BLOCK_SIZE).min(size[1]) {
+                    for px in x * BLOCK_SIZE..=((x + 1) * BLOCK_SIZE).min(size[0]) {
+                        let value = voxels.read(px, py, pz);
+                        range = (range.0.min(value), range.1.max(value));
+                    }
+                }
+            }
+
+            range
+        });
+
+        let mut levels = Vec::new();
+
+        while top.blocks.iter().any(|&blocks| blocks > 1) {
+            let below = top;
+
+            top = MinMaxLevel::from_fn(below.blocks.map(|blocks| blocks.div_ceil(2)), |[x, y, z]| {
+                let mut range = (T::INFINITY, T::NEG_INFINITY);
+
+                for cz in z * 2..(z * 2 + 2).min(below.blocks[2]) {
+                    for cy in y * 2..(y * 2 + 2).min(below.blocks[1]) {
+                        for cx in x * 2..(x * 2 + 2).min(below.blocks[0]) {
+                            let index = below.index([cx, cy, cz]);
+                            range = (range.0.min(below.min[index]), range.1.max(below.max[index]));
+                        }
+                    }
+                }
+
+                range
+            });
+
+            levels.push(below);
+        }
+
+        levels.push(top);
+
+        Self { levels }
+    }
+
+    // Number of levels, the first having blocks of `BLOCK_SIZE` cubes.
+    pub fn levels(&self) -> usize {
+        self.levels.len()
+    }
+
+    // Number of blocks along each axis at `level`.
+    pub fn blocks(&self, level: usize) -> [usize; 3] {
+        self.levels[level].blocks
+    }
+
+    // The smallest and largest sample of a block at `level`.
+    pub fn range(&self, level: usize, block: [usize; 3]) -> (T, T) {
+        let level = &self.levels[level];
+        let index = level.index(block);
+
+        (level.min[index], level.max[index])
+    }
+
+    // The blocks of the first level the surface may pass through, found
+    // from the top down, so that the children of blocks entirely inside
+    // or outside are never looked at.
+    pub fn active_blocks(&self, isolevel: Isolevel<T>) -> ActiveBlocks {
+        let mut active = vec![true];
+
+        for (level, below) in self.levels.iter().enumerate().rev() {
+            let above = self.levels.get(level + 1).map_or([1; 3], |above| above.blocks);
+
+            active = (0..below.min.len())
+                .map(|index| {
+                    let x = index % below.blocks[0];
+                    let y = index / below.blocks[0] % below.blocks[1];
+                    let z = index / below.blocks[0] / below.blocks[1];
+                    let parent = x / 2 + y / 2 * above[0] + z / 2 * above[0] * above[1];
+
+                    // Crossing the level needs samples on both sides of it.
+                    active[parent]
+                        && isolevel.is_inside(below.min[index]) != isolevel.is_inside(below.max[index])
+                })
+                .collect();
+        }
+
+        ActiveBlocks { blocks: self.levels[0].blocks, active }
+    }
+}
+
+// The blocks of `BLOCK_SIZE` cubes that may hold a part of the surface.
+// Cubes in any other block have all their corners on the same side of the
+// isolevel.
+pub struct ActiveBlocks {
+    blocks: [usize; 3],
+    active: Vec<bool>,
+}
+
+impl ActiveBlocks {
+    // Whether the block holding cube (x, y, z) may hold a part of the
+    // surface.
+    pub fn contains(&self, (x, y, z): (usize, usize, usize)) -> bool {
+        let [bx, by, bz] = [x / BLOCK_SIZE, y / BLOCK_SIZE, z / BLOCK_SIZE];
+        self.active[bx + by * self.blocks[0] + bz * self.blocks[0] * self.blocks[1]]
+    }
+
+    // The cubes along x in the row at (y, z) that lie in active blocks, in
+    // order, given the number of cubes along x.
+    pub fn row(&self, y: usize, z: usize, size_x: usize) -> impl Iterator<Item = usize> + '_ {
+        let offset = (y / BLOCK_SIZE + z / BLOCK_SIZE * self.blocks[1]) * self.blocks[0];
+
+        (0..self.blocks[0])
+            .filter(move |&bx| self.active[offset + bx])
+            .flat_map(move |bx| bx * BLOCK_SIZE..((bx + 1) * BLOCK_SIZE).min(size_x))
+    }
+}
@@ -0,0 +1,149 @@
+use glam::{Affine3A, Vec2, Vec3};
+
+use marching_cubes::analysis::analyze;
+use marching_cubes::march_tables::POINTS;
+use marching_cubes::surface_nets::surface_net;
+use marching_cubes::{marching_cubes, Grid, Isolevel, MeshData, Normals, Topology};
+
+fn sphere(x: f32, y: f32, z: f32) -> f32 {
+    Vec3::new(x, y, z).distance(Vec3::new(8.1, 7.7, 8.4)) - 5.3
+}
+
+fn torus(x: f32, y: f32, z: f32) -> f32 {
+    let p = Vec3::new(x, y, z) - Vec3::new(8.2, 7.9, 8.3);
+    Vec2::new(Vec2::new(p.x, p.z).length() - 4.6, p.y).length() - 1.7
+}
+
+fn two_spheres(x: f32, y: f32, z: f32) -> f32 {
+    let ball = |center: Vec3, radius: f32| Vec3::new(x, y, z).distance(center) - radius;
+    ball(Vec3::new(4.3, 5.1, 4.6), 2.9).min(ball(Vec3::new(11.8, 10.2, 11.4), 3.3))
+}
+
+// A 4x4x4 sample grid that is outside everywhere but at the corners of the
+// middle cube, which are inside where `config` has their bit set. The
+// magnitudes differ from corner to corner, so that no crossing lands on
+// another.
+fn middle_cube(config: usize) -> impl Fn(f32, f32, f32) -> f32 {
+    let mut values = vec![1.0; 64];
+
+    for (i, (x, y, z)) in POINTS.into_iter().enumerate() {
+        values[(x + 1) + (y + 1) * 4 + (z + 1) * 16] = if config & (1 << i) != 0 {
+            -(0.5 + i as f32 / 10.0)
+        } else {
+            0.6 + i as f32 / 7.0
+        };
+    }
+
+    move |x, y, z| values[x as usize + y as usize * 4 + z as usize * 16]
+}
+
+fn tetrahedron() -> MeshData {
+    let positions = vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    MeshData::new(positions, Vec::new(), vec![0, 1, 2, 0, 3, 1, 0, 2, 3, 1, 3, 2])
+}
+
+#[test]
+fn every_cube_configuration_is_watertight() {
+    for topology in [Topology::Classic, Topology::Mc33] {
+        for config in 0..256 {
+            let mesh = marching_cubes(Grid::cube(3), &middle_cube(config), Isolevel::default(), topology, Normals::Area);
+            let report = analyze(&mesh);
+
+            assert!(report.is_valid(), "{:?} {:#010b}: {:?}", topology, config, report);
+            assert_eq!(mesh.is_empty(), config == 0);
+
+            // Inside a single cube, every piece is the shape of a sphere.
+            assert_eq!(report.euler_characteristic, 2 * report.components as i64, "{:?} {:#010b}", topology, config);
+        }
+    }
+}
+
+#[test]
+fn sample_fields_have_their_euler_characteristic() {
+    let grid = Grid::cube(17);
+
+    for (field, components, euler_characteristic) in [
+        (&sphere as &dyn Fn(f32, f32, f32) -> f32, 1, 2),
+        (&torus, 1, 0),
+        (&two_spheres, 2, 4),
+    ] {
+        let meshes = [
+            marching_cubes(grid, field, Isolevel::default(), Topology::Classic, Normals::Area),
+            marching_cubes(grid, field, Isolevel::default(), Topology::Mc33, Normals::Area),
+            surface_net(grid, field, Isolevel::default()),
+        ];
+
+        for mesh in meshes {
+            let report = analyze(&mesh);
+
+            assert!(report.is_watertight(), "{:?}", report);
+            assert!(report.degenerate_triangles.is_empty());
+            assert_eq!(report.components, components);
+            assert_eq!(report.euler_characteristic, euler_characteristic);
+        }
+    }
+}
+
+#[test]
+fn surfaces_leaving_the_grid_are_open() {
+    // The grid cuts off the top of the sphere, which leaves a disk.
+    let mesh = marching_cubes(Grid::new([17, 12, 17]), &sphere, Isolevel::default(), Topology::Classic, Normals::Area);
+    let report = analyze(&mesh);
+
+    assert!(!report.is_closed());
+    assert!(report.is_manifold() && report.is_oriented());
+    assert_eq!(report.euler_characteristic, 1);
+
+    for &[a, b] in &report.boundary_edges {
+        for v in [a, b] {
+            assert_eq!(mesh.positions[v as usize][1], 12.0);
+        }
+    }
+}
+
+#[test]
+fn defects_are_reported() {
+    let report = analyze(&tetrahedron());
+    assert!(report.is_valid());
+    assert_eq!(report.euler_characteristic, 2);
+
+    // One triangle wound the other way round.
+    let mut flipped = tetrahedron();
+    flipped.indices.swap(1, 2);
+    let report = analyze(&flipped);
+    assert!(report.is_closed() && report.is_manifold());
+    assert_eq!(report.misoriented_edges, vec![[0, 1], [0, 2], [1, 2]]);
+
+    // A missing triangle leaves a hole.
+    let mut open = tetrahedron();
+    open.indices.truncate(9);
+    let report = analyze(&open);
+    assert_eq!(report.boundary_edges, vec![[1, 2], [1, 3], [2, 3]]);
+    assert_eq!(report.euler_characteristic, 1);
+
+    // Two tetrahedra touching at a vertex, with the mirrored one using the
+    // first vertex instead of its own.
+    let mut touching = tetrahedron();
+    let mut mirrored = tetrahedron();
+    mirrored.transform(Affine3A::from_scale(Vec3::splat(-1.0)));
+    touching.merge(&mirrored);
+    for index in touching.indices.iter_mut().skip(12) {
+        if *index == 4 {
+            *index = 0;
+        }
+    }
+    let report = analyze(&touching);
+    assert!(report.is_closed() && report.is_oriented());
+    assert_eq!(report.non_manifold_vertices, vec![0]);
+    assert!(report.non_manifold_edges.is_empty());
+    assert_eq!(report.components, 1);
+
+    // A fin on one of the edges, and triangles without area.
+    let mut finned = tetrahedron();
+    finned.positions.extend([[0.5, -1.0, 0.0], [2.0, 0.0, 0.0]]);
+    finned.indices.extend([0, 4, 1, 0, 1, 5, 2, 2, 3]);
+    let report = analyze(&finned);
+    assert_eq!(report.non_manifold_edges, vec![[0, 1]]);
+    assert_eq!(report.zero_area_triangles, vec![5]);
+    assert_eq!(report.degenerate_triangles, vec![6]);
+}
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use glam::Vec3;
+
+use marching_cubes::march_tables::{EDGES, POINTS, TRIANGULATIONS};
+use marching_cubes::surface_nets::surface_net;
+use marching_cubes::{marching_cubes, Grid, Inside, Isolevel, MeshData, Normals, Topology, VoxelGrid};
+
+fn grid() -> Grid {
+    Grid::new([23, 17, 19]).with_origin([-1.5, 0.25, 2.0]).with_spacing([0.4, 0.55, 0.45])
+}
+
+// Blobs and holes of every size, with plenty of cubes on ambiguous faces.
+fn waves(x: f32, y: f32, z: f32) -> f32 {
+    (x * 1.3).sin() * (y * 0.9 + 0.4).cos() + (z * 1.7 - x * 0.6).sin() * 0.7 + (y * 2.3).cos() * 0.3
+}
+
+fn isolevels() -> [Isolevel; 2] {
+    [Isolevel::new(0.15, Inside::Below), Isolevel::new(-0.2, Inside::Above)]
+}
+
+fn area_normals(positions: &[[f32; 3]], indices: &[u32]) -> Vec<[f32; 3]> {
+    let mut normals = vec![Vec3::ZERO; positions.len()];
+
+    for triangle in indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|k| Vec3::from(positions[triangle[k] as usize]));
+        let n = (c - a).cross(b - a);
+
+        for &i in triangle {
+            normals[i as usize] += n;
+        }
+    }
+
+    normals.into_iter().map(|n| n.normalize().into()).collect()
+}
+
+// Marching cubes the way it was before the slab caches, with the vertices
+// welded through a map of doubled edge-midpoint coordinates.
+fn reference_marching_cubes(grid: Grid, isolevel: Isolevel) -> MeshData {
+    let voxels = VoxelGrid::from_scalar_field(grid, &waves);
+
+    let mut positions = Vec::new();
+    let mut indices = Vec::new();
+    let mut vertices = HashMap::new();
+
+    for z in 0..grid.size[2] {
+        for y in 0..grid.size[1] {
+            for x in 0..grid.size[0] {
+                let config = (0..8).fold(0, |config, i| {
+                    let (x0, y0, z0) = POINTS[i];
+                    config | (isolevel.is_inside(voxels.read(x + x0, y + y0, z + z0)) as usize) << i
+                });
+
+                for &edge_index in TRIANGULATIONS[config].iter().take_while(|e| !e.is_negative()) {
+                    let (a, b) = EDGES[edge_index as usize];
+                    let ((x0, y0, z0), (x1, y1, z1)) = (POINTS[a], POINTS[b]);
+
+                    let key = [x * 2 + x0 + x1, y * 2 + y0 + y1, z * 2 + z0 + z1];
+
+                    let index = *vertices.entry(key).or_insert_with(|| {
+                        let pos_a = Vec3::new((x + x0) as f32, (y + y0) as f32, (z + z0) as f32);
+                        let pos_b = Vec3::new((x + x1) as f32, (y + y1) as f32, (z + z1) as f32);
+
+                        let val_a = voxels.read(x + x0, y + y0, z + z0);
+                        let val_b = voxels.read(x + x1, y + y1, z + z1);
+
+                        let t = isolevel.crossing(val_a, val_b);
+
+                        positions.push(grid.to_world((pos_a + (pos_b - pos_a) * t).into()));
+                        positions.len() as u32 - 1
+                    });
+
+                    indices.push(index);
+                }
+            }
+        }
+    }
+
+    let normals = area_normals(&positions, &indices);
+
+    MeshData::new(positions, normals, indices)
+}
+
+// Surface nets the way they were before the slab caches, with the vertex
+// of every cell kept in a map.
+fn reference_surface_net(grid: Grid, isolevel: Isolevel) -> MeshData {
+    let voxels = VoxelGrid::from_scalar_field(grid, &waves);
+    let corner = |[x, y, z]: [usize; 3], i: usize| voxels.read(x + (i >> 2 & 1), y + (i >> 1 & 1), z + (i & 1));
+
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut indices = Vec::new();
+    let mut cells = HashMap::new();
+
+    for z in 0..grid.size[2] {
+        for y in 0..grid.size[1] {
+            for x in 0..grid.size[0] {
+                let values = [0, 1, 2, 3, 4, 5, 6, 7].map(|i| corner([x, y, z], i));
+
+                let mut count = 0;
+                let mut sum = [0.0; 3];
+
+                for (a, b) in [(0, 1), (0, 2), (0, 4), (1, 3), (1, 5), (2, 3), (2, 6), (3, 7), (4, 5), (4, 6), (5, 7), (6, 7)] {
+                    if isolevel.is_inside(values[a]) == isolevel.is_inside(values[b]) {
+                        continue;
+                    }
+
+                    let t = isolevel.crossing(values[a], values[b]);
+
+                    for (axis, shift) in [2, 1, 0].into_iter().enumerate() {
+                        sum[axis] += (a >> shift & 1) as f32 * (1.0 - t) + (b >> shift & 1) as f32 * t;
+                    }
+
+                    count += 1;
+                }
+
+                if count == 0 {
+                    continue;
+                }
+
+                let side = |bit: usize, set: bool| (0..8).filter(|i| (i >> bit & 1 == 1) == set).map(|i| values[i]).sum::<f32>();
+                let normal = [2, 1, 0].map(|bit| side(bit, true) - side(bit, false));
+                let normal = [0, 1, 2].map(|axis| normal[axis] / grid.spacing[axis]);
+                let normal = isolevel.orient(normal);
+                let length = (normal[0] * normal[0] + normal[1] * normal[1] + normal[2] * normal[2]).sqrt();
+
+                cells.insert([x, y, z], positions.len());
+                positions.push(grid.to_world([
+                    sum[0] / count as f32 + x as f32,
+                    sum[1] / count as f32 + y as f32,
+                    sum[2] / count as f32 + z as f32,
+                ]));
+                normals.push(normal.map(|n| n / length));
+            }
+        }
+    }
+
+    for z in 0..grid.size[2] {
+        for y in 0..grid.size[1] {
+            for x in 0..grid.size[0] {
+                let cell = [x, y, z];
+
+                // The edge along `axis` from the first corner of the cell,
+                // and the cells around it, in the order of the other two axes.
+                for (axis, axis1, axis2) in [(0, 1, 2), (1, 2, 0), (2, 0, 1)] {
+                    if cell[axis1] == 0 || cell[axis2] == 0 {
+                        continue;
+                    }
+
+                    let mut end = cell;
+                    end[axis] += 1;
+
+                    let inside = [cell, end].map(|[x, y, z]| isolevel.is_inside(voxels.read(x, y, z)));
+
+                    if inside[0] == inside[1] {
+                        continue;
+                    }
+
+                    // The cell `d1` and `d2` cells back along the other two
+                    // axes. Written as one step rather than nested calls of a
+                    // closure stepping back along one axis, which rustc 1.95
+                    // miscompiles at opt-level 1.
+                    let around = |d1: usize, d2: usize| {
+                        let mut around = cell;
+                        around[axis1] -= d1;
+                        around[axis2] -= d2;
+                        around
+                    };
+
+                    let [v1, v2, v3, v4] = [around(0, 0), around(1, 0), around(0, 1), around(1, 1)]
+                        .map(|cell| cells[&cell] as u32);
+
+                    let distance = |a: u32, b: u32| Vec3::from(positions[a as usize]).distance_squared(positions[b as usize].into());
+
+                    let quad = match (distance(v1, v4) < distance(v2, v3), inside[0]) {
+                        (true, true) => [v1, v4, v2, v1, v3, v4],
+                        (true, false) => [v1, v2, v4, v1, v4, v3],
+                        (false, true) => [v2, v3, v4, v2, v1, v3],
+                        (false, false) => [v2, v4, v3, v2, v3, v1],
+                    };
+
+                    indices.extend(quad);
+                }
+            }
+        }
+    }
+
+    MeshData::new(positions, normals, indices)
+}
+
+#[test]
+fn slab_caches_weld_like_a_map() {
+    for isolevel in isolevels() {
+        let expected = reference_marching_cubes(grid(), isolevel);
+        let mesh = marching_cubes(grid(), &waves, isolevel, Topology::Classic, Normals::Area);
+
+        assert!(mesh.triangle_count() > 500);
+        assert_eq!(mesh.positions, expected.positions);
+        assert_eq!(mesh.indices, expected.indices);
+        assert_eq!(mesh.normals, expected.normals);
+    }
+}
+
+#[test]
+fn cell_caches_join_like_a_map() {
+    for isolevel in isolevels() {
+        let expected = reference_surface_net(grid(), isolevel);
+        let mesh = surface_net(grid(), &waves, isolevel);
+
+        assert!(mesh.triangle_count() > 500);
+        assert_eq!(mesh.positions, expected.positions);
+        assert_eq!(mesh.indices, expected.indices);
+        assert_eq!(mesh.normals, expected.normals);
+    }
+}
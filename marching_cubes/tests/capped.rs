@@ -0,0 +1,113 @@
+use glam::Vec3;
+
+use marching_cubes::analysis::analyze;
+use marching_cubes::surface_nets::surface_net_capped;
+use marching_cubes::{marching_cubes, marching_cubes_capped, Grid, Inside, Isolevel, MeshData, Normals, Topology};
+
+// Small xorshift generator, so that the fields are the same on every run.
+struct Random(u32);
+
+impl Random {
+    fn next(&mut self) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        (self.0 >> 8) as f32 / (1 << 24) as f32
+    }
+}
+
+// Leaves the grid through three of its faces.
+fn sphere(x: f32, y: f32, z: f32) -> f32 {
+    Vec3::new(x, y, z).distance(Vec3::new(8.1, 7.7, 8.4)) - 5.3
+}
+
+// Solid below a tilted plane, which covers the whole bottom of the grid.
+fn ground(x: f32, y: f32, _z: f32) -> f32 {
+    y - 4.3 - 0.2 * x
+}
+
+fn noise(random: &mut Random) -> impl Fn(f32, f32, f32) -> f32 {
+    let values: Vec<f32> = (0..13 * 13 * 13).map(|_| random.next() * 2.0 - 1.0).collect();
+    move |x, y, z| values[x as usize + y as usize * 13 + z as usize * 169]
+}
+
+// Enclosed volume, from the signed volumes of the tetrahedra between the
+// origin and every triangle.
+fn volume(mesh: &MeshData) -> f32 {
+    let volume: f32 = mesh.indices
+        .chunks_exact(3)
+        .map(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|k| Vec3::from(mesh.positions[triangle[k] as usize]));
+            a.dot(c.cross(b)) / 6.0
+        })
+        .sum();
+
+    volume.abs()
+}
+
+#[test]
+fn capped_marching_cubes_are_watertight() {
+    let mut random = Random(0x2545_f491);
+    let noise = noise(&mut random);
+
+    for field in [&sphere as &dyn Fn(f32, f32, f32) -> f32, &ground, &noise] {
+        for topology in [Topology::Classic, Topology::Mc33] {
+            let open = marching_cubes(Grid::cube(12), field, Isolevel::default(), topology, Normals::Area);
+            let capped = marching_cubes_capped(Grid::cube(12), field, Isolevel::default(), topology, Normals::Area);
+
+            assert!(!analyze(&open).is_closed());
+
+            let report = analyze(&capped);
+            assert!(report.is_valid(), "{:?}: {:?}", topology, report);
+
+            // The caps only add vertices and triangles to the ones inside
+            // the grid.
+            assert_eq!(capped.positions[..open.vertex_count()], open.positions[..]);
+            assert_eq!(capped.indices[..open.indices.len()], open.indices[..]);
+        }
+    }
+}
+
+#[test]
+fn capped_surface_nets_are_closed() {
+    let mut random = Random(0x9e37_79b9);
+    let noise = noise(&mut random);
+
+    for field in [&sphere as &dyn Fn(f32, f32, f32) -> f32, &ground] {
+        let report = analyze(&surface_net_capped(Grid::cube(12), field, Isolevel::default()));
+        assert!(report.is_valid(), "{:?}", report);
+        assert_eq!(report.euler_characteristic, 2);
+    }
+
+    // Surface nets can pinch on noise, but never leave holes.
+    let report = analyze(&surface_net_capped(Grid::cube(12), &noise, Isolevel::default()));
+    assert!(report.is_closed() && report.is_oriented());
+}
+
+#[test]
+fn caps_lie_on_the_faces_of_the_grid() {
+    let grid = Grid::cube(12).with_origin([-2.0, 1.0, 0.5]).with_spacing([0.5, 0.75, 1.0]);
+    let ground = |x: f32, y: f32, z: f32| ground((x + 2.0) / 0.5, (y - 1.0) / 0.75, z);
+
+    let mesh = marching_cubes_capped(grid, &ground, Isolevel::default(), Topology::Classic, Normals::Area);
+
+    // A linear field comes out exactly, so the volume is the one under the
+    // plane: the mean height over the grid is 5.5, of 12 cells.
+    let expected = 12.0 * 0.5 * 5.5 * 0.75 * 12.0;
+    assert!((volume(&mesh) - expected).abs() < 1e-2, "{}", volume(&mesh));
+
+    let (low, high) = (Vec3::new(-2.0, 1.0, 0.5), Vec3::new(4.0, 10.0, 12.5));
+
+    for &position in &mesh.positions {
+        let position = Vec3::from(position);
+        assert!(position.cmpge(low).all() && position.cmple(high).all());
+    }
+
+    // Flipping which side is inside caps the other part of the grid.
+    let above = Isolevel::new(0.0, Inside::Above);
+    let flipped = marching_cubes_capped(grid, &ground, above, Topology::Classic, Normals::Area);
+    let report = analyze(&flipped);
+
+    assert!(report.is_valid());
+    assert!((volume(&flipped) - (6.0 * 9.0 * 12.0 - expected)).abs() < 1e-2, "{}", volume(&flipped));
+}
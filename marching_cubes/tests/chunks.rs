@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use glam::Vec3;
+
+use marching_cubes::{
+    marching_cubes, marching_cubes_chunks, marching_cubes_welded, Chunks, Grid, Isolevel, MeshData, Normals, Topology,
+};
+
+// Wavy layers that cross every chunk border.
+fn waves(x: f32, y: f32, z: f32) -> f32 {
+    (x * 0.9).sin() + (y * 1.1 + 0.3).cos() + (z * 0.7 + x * 0.4).sin() * 0.8 - 0.2
+}
+
+fn chunks() -> Chunks {
+    Chunks::new(Grid::new([6, 5, 7]).with_origin([-1.3, 0.4, 2.1]).with_spacing([0.5, 0.6, 0.45]), [3, 2, 2])
+}
+
+// Every triangle, by the bits of its positions.
+fn triangles(mesh: &MeshData) -> Vec<[[u32; 3]; 3]> {
+    let mut triangles: Vec<_> = mesh.indices
+        .chunks_exact(3)
+        .map(|triangle| [0, 1, 2].map(|k| mesh.positions[triangle[k] as usize].map(f32::to_bits)))
+        .collect();
+
+    triangles.sort();
+    triangles
+}
+
+// Normals by the bits of the positions they are at.
+fn normals(mesh: &MeshData) -> HashMap<[u32; 3], Vec3> {
+    mesh.positions.iter().zip(&mesh.normals).map(|(p, &n)| (p.map(f32::to_bits), n.into())).collect()
+}
+
+// The largest difference in normal between vertices at the same position
+// in different meshes.
+fn seam_mismatch(meshes: &[MeshData]) -> f32 {
+    let mut seen: HashMap<[u32; 3], Vec3> = HashMap::new();
+    let mut mismatch: f32 = 0.0;
+
+    for mesh in meshes {
+        for (position, normal) in normals(mesh) {
+            if let Some(&other) = seen.get(&position) {
+                mismatch = mismatch.max(other.distance(normal));
+            }
+
+            seen.insert(position, normal);
+        }
+    }
+
+    mismatch
+}
+
+#[test]
+fn welded_chunks_match_the_whole_volume() {
+    for topology in [Topology::Classic, Topology::Mc33] {
+        for normals_kind in [Normals::Area, Normals::Angle, Normals::Gradient { epsilon: 2 }] {
+            let whole = marching_cubes(chunks().volume(), &waves, Isolevel::default(), topology, normals_kind);
+            let welded = marching_cubes_welded(chunks(), &waves, Isolevel::default(), topology, normals_kind);
+
+            assert_eq!(welded.vertex_count(), whole.vertex_count());
+            assert_eq!(triangles(&welded), triangles(&whole));
+
+            let whole_normals = normals(&whole);
+
+            for (position, normal) in normals(&welded) {
+                assert!(normal.distance(whole_normals[&position]) < 1e-5, "{:?} {:?}", topology, normals_kind);
+            }
+        }
+    }
+}
+
+#[test]
+fn chunk_borders_share_positions_and_normals() {
+    let meshes = marching_cubes_chunks(chunks(), &waves, Isolevel::default(), Topology::Classic, Normals::Area);
+    assert_eq!(meshes.len(), 12);
+
+    let mut merged = MeshData::default();
+
+    for mesh in &meshes {
+        merged.merge(mesh);
+    }
+
+    let whole = marching_cubes(chunks().volume(), &waves, Isolevel::default(), Topology::Classic, Normals::Area);
+    assert_eq!(triangles(&merged), triangles(&whole));
+
+    assert!(seam_mismatch(&meshes) < 1e-5);
+
+    // Meshing the chunk grids on their own leaves visible seams.
+    let separate: Vec<_> = chunks()
+        .coords()
+        .map(|coord| marching_cubes(chunks().grid(coord), &waves, Isolevel::default(), Topology::Classic, Normals::Area))
+        .collect();
+
+    assert!(seam_mismatch(&separate) > 0.1);
+}
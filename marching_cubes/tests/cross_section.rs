@@ -0,0 +1,79 @@
+use glam::Vec3;
+
+use marching_cubes::cross_section::{cross_section, sample_plane, section_lines, Plane};
+use marching_cubes::marching_squares::{contour_lines, Ambiguity, Grid2d, PixelGrid};
+use marching_cubes::{Grid, Isolevel, VoxelGrid};
+
+const CENTER: Vec3 = Vec3::new(4.2, 3.9, 5.1);
+
+fn sphere(x: f32, y: f32, z: f32) -> f32 {
+    Vec3::new(x, y, z).distance(CENTER) - 3.0
+}
+
+#[test]
+fn tilted_plane_cuts_a_circle() {
+    let normal = Vec3::new(1.0, 2.0, -0.5).normalize();
+    let plane = Plane::from_normal((CENTER + normal).into(), normal.into());
+    let grid = Grid2d::square(40).with_origin([-4.0, -4.0]).with_spacing([0.2, 0.2]);
+
+    let lines = cross_section(plane, grid, &sphere, Isolevel::default(), Ambiguity::Separate);
+
+    assert_eq!(lines.len(), 1);
+    assert!(lines[0].closed);
+
+    // Distance 1 from the center, so the circle has a radius of sqrt(8).
+    let circle_center = CENTER + normal;
+
+    for &point in &lines[0].points {
+        let point = Vec3::from(point);
+        assert!((point - circle_center).dot(normal).abs() < 1e-4);
+        assert!(((point - circle_center).length() - 8f32.sqrt()).abs() < 0.02, "{}", point);
+    }
+
+    // Counter-clockwise seen from the normal, around the inside.
+    let points = &lines[0].points;
+    let winding: f32 = (0..points.len())
+        .map(|i| {
+            let (a, b) = (Vec3::from(points[i]), Vec3::from(points[(i + 1) % points.len()]));
+            (a - circle_center).cross(b - circle_center).dot(normal)
+        })
+        .sum();
+
+    assert!(winding > 0.0);
+}
+
+#[test]
+fn axis_plane_matches_a_voxel_slice() {
+    let grid = Grid::new([16, 14, 20]).with_origin([0.5, 0.0, 1.0]).with_spacing([0.5; 3]);
+    let voxel_grid = VoxelGrid::from_scalar_field(grid, &sphere);
+
+    // The grid points at z index 8, at z = 5.
+    let slice = PixelGrid::from_voxel_slice(&voxel_grid, 2, 8);
+
+    let plane = Plane::new([0.0, 0.0, 5.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]);
+    let image = sample_plane(plane, slice.grid(), &sphere);
+
+    let expected = contour_lines(&slice, Isolevel::default(), Ambiguity::Separate);
+    let lines = section_lines(plane, &image, Isolevel::default(), Ambiguity::Separate);
+
+    assert_eq!(lines.len(), expected.len());
+
+    for (line, expected) in lines.iter().zip(&expected) {
+        assert_eq!(line.closed, expected.closed);
+
+        for (&point, &[x, y]) in line.points.iter().zip(&expected.points) {
+            assert!(Vec3::from(point).abs_diff_eq(Vec3::new(x, y, 5.0), 1e-5));
+        }
+    }
+}
+
+#[test]
+fn planes_missing_the_surface_are_empty() {
+    let plane = Plane::from_normal([0.0, 0.0, 20.0], [0.0, 0.0, 1.0]);
+    let grid = Grid2d::square(10).with_origin([-5.0, -5.0]);
+
+    assert!(cross_section(plane, grid, &sphere, Isolevel::default(), Ambiguity::Separate).is_empty());
+
+    let image = sample_plane(plane, grid, &sphere);
+    assert!((image.read(5, 5) - (CENTER.distance(Vec3::from(plane.to_world([0.0, 0.0]))) - 3.0)).abs() < 1e-5);
+}
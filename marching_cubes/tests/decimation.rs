@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use glam::Vec3;
+
+use marching_cubes::decimation::{decimate, Decimation};
+use marching_cubes::mesh_data::{Attribute, AttributeValues};
+use marching_cubes::{marching_cubes, Grid, Isolevel, MeshData, Normals, Topology};
+
+// Hills on otherwise flat ground.
+fn terrain(x: f32, y: f32, z: f32) -> f32 {
+    let hill = |cx: f32, cz: f32| 3.0 * (-((x - cx) * (x - cx) + (z - cz) * (z - cz)) / 2.0).exp();
+    y - 4.3 - hill(6.0, 7.0) - hill(20.0, 14.0)
+}
+
+fn sphere(x: f32, y: f32, z: f32) -> f32 {
+    Vec3::new(x, y, z).distance(Vec3::splat(8.1)) - 5.3
+}
+
+// Number of triangles on each undirected edge of a mesh.
+fn edge_uses(mesh: &MeshData) -> HashMap<(u32, u32), usize> {
+    let mut uses = HashMap::new();
+
+    for triangle in mesh.indices.chunks_exact(3) {
+        for k in 0..3 {
+            let (a, b) = (triangle[k], triangle[(k + 1) % 3]);
+            *uses.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+        }
+    }
+
+    uses
+}
+
+// Positions on edges used by a single triangle, in a canonical order.
+fn border(mesh: &MeshData) -> Vec<[u32; 3]> {
+    let mut border: Vec<_> = edge_uses(mesh)
+        .into_iter()
+        .filter(|&(_, uses)| uses == 1)
+        .flat_map(|((a, b), _)| [a, b])
+        .map(|v| mesh.positions[v as usize].map(f32::to_bits))
+        .collect();
+
+    border.sort();
+    border.dedup();
+    border
+}
+
+fn triangle_normal(mesh: &MeshData, triangle: &[u32]) -> Vec3 {
+    let [a, b, c] = [0, 1, 2].map(|k| Vec3::from(mesh.positions[triangle[k] as usize]));
+    (c - a).cross(b - a)
+}
+
+#[test]
+fn flat_ground_collapses_and_chunk_borders_match() {
+    let chunks = [Grid::cube(16), Grid::cube(16).with_origin([16.0, 0.0, 0.0])];
+    let meshes = chunks.map(|grid| marching_cubes(grid, &terrain, Isolevel::default(), Topology::Classic, Normals::Area));
+    let decimated = meshes.clone().map(|mesh| decimate(&mesh, Decimation::new(0).with_max_error(1e-4)));
+
+    for (mesh, decimated) in meshes.iter().zip(&decimated) {
+        assert!(decimated.triangle_count() * 2 < mesh.triangle_count(), "{} of {}", decimated.triangle_count(), mesh.triangle_count());
+        assert_eq!(border(decimated), border(mesh));
+
+        // Only flat parts went, so vertices are as close to the surface as
+        // they were.
+        let furthest = |mesh: &MeshData| mesh.positions.iter().map(|&[x, y, z]| terrain(x, y, z).abs()).fold(0.0, f32::max);
+        assert!(furthest(decimated) <= furthest(mesh) + 1e-3);
+    }
+
+    // The shared face at x = 16 still has the same vertices on both sides.
+    let seam = |mesh: &MeshData| {
+        let mut seam: Vec<_> = border(mesh).into_iter().filter(|p| f32::from_bits(p[0]) == 16.0).collect();
+        seam.sort();
+        seam
+    };
+
+    assert!(!seam(&decimated[0]).is_empty());
+    assert_eq!(seam(&decimated[0]), seam(&decimated[1]));
+}
+
+#[test]
+fn closed_meshes_stay_closed_and_facing_out() {
+    let mesh = marching_cubes(Grid::cube(16), &sphere, Isolevel::default(), Topology::Classic, Normals::Area);
+    let target = mesh.triangle_count() / 10;
+
+    let decimated = decimate(&mesh, Decimation::new(target));
+
+    assert!(decimated.triangle_count() <= target && decimated.triangle_count() + 2 >= target);
+    assert!(edge_uses(&decimated).values().all(|&uses| uses == 2));
+
+    for triangle in decimated.indices.chunks_exact(3) {
+        let center = triangle.iter().fold(Vec3::ZERO, |sum, &v| sum + Vec3::from(decimated.positions[v as usize])) / 3.0;
+        assert!(triangle_normal(&decimated, triangle).dot(center - Vec3::splat(8.1)) > 0.0);
+    }
+
+    for &[x, y, z] in &decimated.positions {
+        assert!(sphere(x, y, z).abs() < 0.3, "{}", sphere(x, y, z));
+    }
+}
+
+#[test]
+fn decimation_keeps_vertex_data() {
+    let mesh = marching_cubes(Grid::cube(12), &sphere, Isolevel::default(), Topology::Classic, Normals::Gradient { epsilon: 1 });
+    let heights = mesh.positions.iter().map(|p| p[1]).collect();
+    let mesh = mesh.with_attribute(Attribute { name: "Height", id: 0, values: AttributeValues::Float32(heights) });
+
+    let decimated = decimate(&mesh, Decimation::new(mesh.triangle_count() / 2));
+
+    assert_eq!(decimated.normals.len(), decimated.vertex_count());
+
+    let AttributeValues::Float32(heights) = &decimated.attributes[0].values else { panic!() };
+    assert_eq!(heights.len(), decimated.vertex_count());
+
+    // Vertices that didn't move kept their own values.
+    for (position, height) in decimated.positions.iter().zip(heights) {
+        if mesh.positions.contains(position) {
+            assert_eq!(position[1], *height);
+        }
+    }
+
+    // Nothing left to collapse within a zero error on a sphere.
+    assert_eq!(decimate(&mesh, Decimation::new(0).with_max_error(0.0)).triangle_count(), mesh.triangle_count());
+}
@@ -0,0 +1,76 @@
+use glam::Vec3;
+
+use marching_cubes::{dual_contouring::dual_contour, surface_nets::surface_net, Grid, Inside, Isolevel, MeshData};
+
+// A box from 2.3 to 7.6 along each axis, so that none of its faces lie on
+// the grid.
+fn cuboid(x: f32, y: f32, z: f32) -> f32 {
+    let distance = (Vec3::new(x, y, z) - Vec3::splat(4.95)).abs() - Vec3::splat(2.65);
+    distance.max(Vec3::ZERO).length() + distance.max_element().min(0.0)
+}
+
+fn corners() -> impl Iterator<Item = Vec3> {
+    (0..8).map(|i| Vec3::new([2.3, 7.6][i & 1], [2.3, 7.6][i >> 1 & 1], [2.3, 7.6][i >> 2 & 1]))
+}
+
+fn closest_vertex(mesh: &MeshData, target: Vec3) -> f32 {
+    mesh.positions.iter().map(|&position| Vec3::from(position).distance(target)).fold(f32::INFINITY, f32::min)
+}
+
+#[test]
+fn dual_contouring_keeps_corners() {
+    let mesh = dual_contour(Grid::cube(10), &cuboid, Isolevel::default());
+    let net = surface_net(Grid::cube(10), &cuboid, Isolevel::default());
+
+    for corner in corners() {
+        assert!(closest_vertex(&mesh, corner) < 1e-3, "{}", corner);
+        assert!(closest_vertex(&net, corner) > 0.1);
+    }
+}
+
+#[test]
+fn dual_contouring_stays_on_the_surface() {
+    let mesh = dual_contour(Grid::cube(10), &cuboid, Isolevel::default());
+
+    for &position in &mesh.positions {
+        let [x, y, z] = position;
+        assert!(cuboid(x, y, z).abs() < 1e-3, "{:?}", position);
+    }
+
+    // Same topology as surface nets, only with moved vertices.
+    let net = surface_net(Grid::cube(10), &cuboid, Isolevel::default());
+
+    assert_eq!(mesh.indices.len(), net.indices.len());
+}
+
+#[test]
+fn dual_contouring_keeps_vertices_in_their_cells() {
+    // A sphere has no features to find, and close to flat planes must not
+    // send vertices far away.
+    let sphere = |x: f32, y: f32, z: f32| Vec3::new(x, y, z).distance(Vec3::splat(2.5)) - 1.7;
+    let grid = Grid::new([10, 10, 10]).with_origin([0.1, 0.0, -0.2]).with_spacing([0.5, 0.5, 0.6]);
+
+    let mesh = dual_contour(grid, &sphere, Isolevel::default());
+    let net = surface_net(grid, &sphere, Isolevel::default());
+
+    assert_eq!(mesh.vertex_count(), net.vertex_count());
+
+    for (&vertex, &center) in mesh.positions.iter().zip(net.positions.iter()) {
+        let [x, y, z] = vertex;
+        let offset = (Vec3::from(vertex) - Vec3::from(center)).abs();
+
+        assert!(offset.cmple(Vec3::from(grid.spacing)).all());
+        assert!(sphere(x, y, z).abs() < 0.1, "{:?}", vertex);
+    }
+}
+
+#[test]
+fn dual_contouring_normals_point_outside() {
+    let inverted = |x, y, z| -cuboid(x, y, z);
+    let mesh = dual_contour(Grid::cube(10), &inverted, Isolevel::new(0.0, Inside::Above));
+
+    for (&position, &normal) in mesh.positions.iter().zip(mesh.normals.iter()) {
+        let outward = Vec3::from(position) - Vec3::splat(4.95);
+        assert!(outward.dot(normal.into()) > 0.0);
+    }
+}
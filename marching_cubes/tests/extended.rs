@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use glam::Vec3;
+
+use marching_cubes::{extended_marching_cubes, marching_cubes, Features, Grid, Isolevel, MeshData, Normals, Topology};
+
+// A box from 2.3 to 7.6 along each axis, so that none of its faces lie on
+// the grid.
+fn cuboid(x: f32, y: f32, z: f32) -> f32 {
+    let distance = (Vec3::new(x, y, z) - Vec3::splat(4.95)).abs() - Vec3::splat(2.65);
+    distance.max(Vec3::ZERO).length() + distance.max_element().min(0.0)
+}
+
+const BOUNDS: [f32; 2] = [2.3, 7.6];
+
+fn closest_vertex(mesh: &MeshData, target: Vec3) -> f32 {
+    mesh.positions.iter().map(|&position| Vec3::from(position).distance(target)).fold(f32::INFINITY, f32::min)
+}
+
+// Number of triangles on each undirected edge of a mesh.
+fn edge_uses(mesh: &MeshData) -> HashMap<(u32, u32), usize> {
+    let mut uses = HashMap::new();
+
+    for triangle in mesh.indices.chunks_exact(3) {
+        for k in 0..3 {
+            let (a, b) = (triangle[k], triangle[(k + 1) % 3]);
+            *uses.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+        }
+    }
+
+    uses
+}
+
+#[test]
+fn extended_marching_cubes_keeps_corners() {
+    let mesh = extended_marching_cubes(Grid::cube(10), &cuboid, Isolevel::default(), Features::default());
+    let regular = marching_cubes(Grid::cube(10), &cuboid, Isolevel::default(), Topology::Classic, Normals::Area);
+
+    for i in 0..8 {
+        let corner = Vec3::new(BOUNDS[i & 1], BOUNDS[i >> 1 & 1], BOUNDS[i >> 2 & 1]);
+
+        assert!(closest_vertex(&mesh, corner) < 1e-3, "{}", corner);
+        assert!(closest_vertex(&regular, corner) > 0.1);
+    }
+
+    for &[x, y, z] in &mesh.positions {
+        assert!(cuboid(x, y, z).abs() < 1e-3);
+    }
+
+    // Still closed, with every edge between two triangles.
+    assert!(edge_uses(&mesh).values().all(|&uses| uses == 2));
+}
+
+#[test]
+fn flipped_edges_run_along_the_box() {
+    let mesh = extended_marching_cubes(Grid::cube(10), &cuboid, Isolevel::default(), Features::default());
+
+    // Length of mesh edges lying on each edge of the box, by the axis it
+    // runs along and the bounds it sits at on the other two.
+    let mut lengths: HashMap<(usize, usize, usize), f32> = HashMap::new();
+
+    for &(a, b) in edge_uses(&mesh).keys() {
+        let (a, b) = (Vec3::from(mesh.positions[a as usize]), Vec3::from(mesh.positions[b as usize]));
+
+        for axis in 0..3 {
+            let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+            let bound = |p: Vec3, c: usize| BOUNDS.iter().position(|&bound| (p[c] - bound).abs() < 1e-3);
+
+            if let (Some(bu), Some(bv)) = (bound(a, u), bound(a, v)) {
+                if bound(b, u) == Some(bu) && bound(b, v) == Some(bv) {
+                    *lengths.entry((axis, bu, bv)).or_insert(0.0) += a.distance(b);
+                }
+            }
+        }
+    }
+
+    assert_eq!(lengths.len(), 12);
+
+    for (edge, length) in lengths {
+        assert!((length - (BOUNDS[1] - BOUNDS[0])).abs() < 1e-2, "{:?}: {}", edge, length);
+    }
+}
+
+#[test]
+fn smooth_fields_keep_the_regular_triangulation() {
+    let sphere = |x: f32, y: f32, z: f32| Vec3::new(x, y, z).distance(Vec3::new(4.1, 3.8, 4.3)) - 2.9;
+    let grid = Grid::new([18, 16, 18]).with_spacing([0.5; 3]);
+
+    let mesh = extended_marching_cubes(grid, &sphere, Isolevel::default(), Features::default());
+    let regular = marching_cubes(grid, &sphere, Isolevel::default(), Topology::Classic, Normals::Area);
+
+    assert_eq!(mesh.vertex_count(), regular.vertex_count());
+    assert_eq!(mesh.triangle_count(), regular.triangle_count());
+
+    for (&[x, y, z], &normal) in mesh.positions.iter().zip(&mesh.normals) {
+        assert!(sphere(x, y, z).abs() < 1e-3);
+
+        let outwards = (Vec3::new(x, y, z) - Vec3::new(4.1, 3.8, 4.3)).normalize();
+        assert!(outwards.dot(normal.into()) > 0.999);
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use glam::Vec3;
+
+use marching_cubes::{
+    marching_cubes_voxels, EditableVoxels, Grid, IncrementalMesh, Isolevel, MeshData, Normals, SparseVoxelGrid, Topology, VoxelGrid, VoxelsMut,
+};
+
+fn grid() -> Grid {
+    Grid::new([24, 20, 22]).with_origin([-1.0, 0.5, 0.0]).with_spacing([0.5, 0.5, 0.5])
+}
+
+fn terrain(x: f32, y: f32, z: f32) -> f32 {
+    y - 4.0 - (x * 0.7).sin() - (z * 0.5).cos()
+}
+
+// Adds (or carves, for a negative `strength`) a ball around a grid point.
+fn brush<V: VoxelsMut>(voxels: &mut EditableVoxels<V>, center: [usize; 3], radius: usize, strength: f32) {
+    let dimensions = voxels.voxels().dimensions();
+    let low = center.map(|c| c.saturating_sub(radius));
+    let high: [usize; 3] = std::array::from_fn(|axis| (center[axis] + radius).min(dimensions[axis] - 1));
+
+    for z in low[2]..=high[2] {
+        for y in low[1]..=high[1] {
+            for x in low[0]..=high[0] {
+                let offset = Vec3::new(x as f32, y as f32, z as f32) - Vec3::new(center[0] as f32, center[1] as f32, center[2] as f32);
+                let falloff = (1.0 - offset.length() / radius as f32).max(0.0);
+                let value = voxels.voxels().read(x, y, z);
+                voxels.write(x, y, z, value - strength * falloff);
+            }
+        }
+    }
+}
+
+// The triangles of a mesh by their corner positions, rotated to start at
+// the smallest corner, in a canonical order.
+fn triangles(mesh: &MeshData) -> Vec<[[u32; 3]; 3]> {
+    let mut triangles: Vec<_> = mesh.indices.chunks_exact(3).map(|triangle| {
+        let corners = [0, 1, 2].map(|k| mesh.positions[triangle[k] as usize].map(f32::to_bits));
+        let first = (0..3).min_by_key(|&k| corners[k]).unwrap();
+        [0, 1, 2].map(|k| corners[(first + k) % 3])
+    }).collect();
+
+    triangles.sort();
+    triangles
+}
+
+fn assert_matches(incremental: &MeshData, full: &MeshData) {
+    assert_eq!(triangles(incremental), triangles(full));
+
+    let normals: HashMap<_, _> = full.positions.iter().zip(&full.normals).map(|(p, &n)| (p.map(f32::to_bits), n)).collect();
+
+    for &index in &incremental.indices {
+        let position = incremental.positions[index as usize];
+        let expected = Vec3::from(normals[&position.map(f32::to_bits)]);
+        let normal = Vec3::from(incremental.normals[index as usize]);
+
+        assert!(normal.distance(expected) < 1e-4, "{normal} != {expected} at {position:?}");
+    }
+}
+
+#[test]
+fn edits_match_meshing_from_scratch() {
+    for topology in [Topology::Classic, Topology::Mc33] {
+        for normals in [Normals::Area, Normals::Angle, Normals::Gradient { epsilon: 2 }] {
+            let mut voxels = EditableVoxels::new(VoxelGrid::from_scalar_field(grid(), &terrain));
+            let mut mesh = IncrementalMesh::new(&voxels, Isolevel::default(), topology, normals);
+
+            assert_matches(mesh.mesh(), &marching_cubes_voxels(&voxels, Isolevel::default(), topology, normals));
+
+            // Bumps, a crater, a tunnel through a ridge, and edits at the
+            // borders of the grid.
+            let edits = [([6, 9, 8], 3, 2.0), ([15, 8, 12], 4, -3.0), ([0, 8, 10], 3, 2.5), ([24, 9, 22], 2, -2.0)];
+
+            for (center, radius, strength) in edits {
+                brush(&mut voxels, center, radius, strength);
+                mesh.update(&mut voxels);
+                assert!(voxels.dirty().is_empty());
+
+                assert_matches(mesh.mesh(), &marching_cubes_voxels(&voxels, Isolevel::default(), topology, normals));
+            }
+
+            // Two strokes before a single update.
+            brush(&mut voxels, [10, 10, 4], 2, 3.0);
+            brush(&mut voxels, [18, 6, 18], 2, -3.0);
+            assert_eq!(voxels.dirty().len(), 2);
+
+            mesh.update(&mut voxels);
+            assert_matches(mesh.mesh(), &marching_cubes_voxels(&voxels, Isolevel::default(), topology, normals));
+        }
+    }
+}
+
+#[test]
+fn untouched_vertices_keep_their_index() {
+    let mut voxels = EditableVoxels::new(SparseVoxelGrid::from_scalar_field(grid(), &terrain));
+    let mut mesh = IncrementalMesh::new(&voxels, Isolevel::default(), Topology::Classic, Normals::Area);
+
+    let before = mesh.mesh().clone();
+    brush(&mut voxels, [12, 9, 11], 3, 2.0);
+    mesh.update(&mut voxels);
+    let after = mesh.mesh();
+
+    // Vertices on edges outside of the brush keep their slot, including
+    // the ones in cubes that were marched again.
+    let far = |position: [f32; 3]| {
+        let [x, y, z] = grid().to_grid(position);
+        (x - 12.0).abs() > 3.0 || (y - 9.0).abs() > 3.0 || (z - 11.0).abs() > 3.0
+    };
+
+    let kept = before.indices.iter().filter(|&&index| far(before.positions[index as usize])).count();
+    assert!(kept > 0);
+
+    for &index in &before.indices {
+        let position = before.positions[index as usize];
+
+        if far(position) {
+            assert_eq!(after.positions[index as usize], position);
+        }
+    }
+
+    // The edit is welded to its surroundings, with as many vertices in use
+    // as meshing from scratch makes.
+    let mut indices = after.indices.clone();
+    indices.sort();
+    indices.dedup();
+
+    let full = marching_cubes_voxels(&voxels, Isolevel::default(), Topology::Classic, Normals::Area);
+    assert_eq!(indices.len(), full.vertex_count());
+}
@@ -0,0 +1,161 @@
+use std::cell::Cell;
+
+use glam::Vec3;
+
+use marching_cubes::interval::{Interval, IntervalField};
+use marching_cubes::{marching_cubes, marching_cubes_pruned, Grid, Inside, Isolevel, MeshData, Normals, ScalarField, Topology};
+
+// The quartic surface of the showcase, on coordinates from -4 to 4.
+struct Quartic;
+
+impl ScalarField for Quartic {
+    fn sample(&self, x: f32, y: f32, z: f32) -> f32 {
+        (x * x - 4.0) * (x * x - 4.0) + (y * y - 4.0) * (y * y - 4.0) + (z * z - 4.0) * (z * z - 4.0)
+            + 3.0 * (x * x * y * y + x * x * z * z + y * y * z * z)
+            + 6.0 * x * y * z
+            - 10.0 * (x * x + y * y + z * z)
+            + 22.0
+    }
+}
+
+impl IntervalField for Quartic {
+    fn interval(&self, x: Interval, y: Interval, z: Interval) -> Interval {
+        let (xx, yy, zz) = (x.square(), y.square(), z.square());
+
+        (xx - 4.0).square() + (yy - 4.0).square() + (zz - 4.0).square()
+            + 3.0 * (xx * yy + xx * zz + yy * zz)
+            + 6.0 * x * y * z
+            - 10.0 * (xx + yy + zz)
+            + 22.0
+    }
+}
+
+// A sphere that counts how often it is sampled.
+struct Sphere {
+    center: Vec3,
+    radius: f32,
+    samples: Cell<usize>,
+}
+
+impl ScalarField for Sphere {
+    fn sample(&self, x: f32, y: f32, z: f32) -> f32 {
+        self.samples.set(self.samples.get() + 1);
+        Vec3::new(x, y, z).distance(self.center) - self.radius
+    }
+}
+
+impl IntervalField for Sphere {
+    fn interval(&self, x: Interval, y: Interval, z: Interval) -> Interval {
+        ((x - self.center.x).square() + (y - self.center.y).square() + (z - self.center.z).square()).sqrt() - self.radius
+    }
+}
+
+// The triangles of a mesh by their corner positions, rotated to start at
+// the smallest corner, in a canonical order.
+fn triangles(mesh: &MeshData) -> Vec<[[u32; 3]; 3]> {
+    let mut triangles: Vec<_> = mesh.indices.chunks_exact(3).map(|triangle| {
+        let corners = [0, 1, 2].map(|k| mesh.positions[triangle[k] as usize].map(f32::to_bits));
+        let first = (0..3).min_by_key(|&k| corners[k]).unwrap();
+        [0, 1, 2].map(|k| corners[(first + k) % 3])
+    }).collect();
+
+    triangles.sort();
+    triangles
+}
+
+#[test]
+fn interval_operations_hold_their_results() {
+    let intervals = [Interval::new(-2.0, 3.0), Interval::new(0.5, 1.5), Interval::new(-4.0, -1.0), Interval::point(0.0)];
+    let samples = |interval: Interval| (0..=10).map(move |i| interval.min + (interval.max - interval.min) * i as f32 / 10.0);
+
+    for &a in &intervals {
+        for x in samples(a) {
+            assert!(a.square().contains(x * x));
+            assert!(a.abs().contains(x.abs()));
+            assert!((-a).contains(-x));
+            assert!(a.sqrt().contains(x.max(0.0).sqrt()));
+
+            for &b in &intervals {
+                for y in samples(b) {
+                    assert!((a + b).contains(x + y));
+                    assert!((a - b).contains(x - y));
+                    assert!((a * b).contains(x * y));
+                    assert!(a.min(b).contains(x.min(y)));
+                    assert!(a.max(b).contains(x.max(y)));
+                }
+            }
+        }
+    }
+
+    assert_eq!(Interval::new(-2.0, 3.0).square(), Interval::new(0.0, f64::next_up(9.0)));
+}
+
+#[test]
+fn pruning_keeps_the_triangles() {
+    let grid = Grid::cube(40).with_origin([-4.0; 3]).with_spacing([0.2; 3]);
+
+    for topology in [Topology::Classic, Topology::Mc33] {
+        for isolevel in [Isolevel::default(), Isolevel::new(2.0, Inside::Above)] {
+            let pruned = marching_cubes_pruned(grid, &Quartic, isolevel, topology, Normals::Area);
+            let full = marching_cubes(grid, &Quartic, isolevel, topology, Normals::Area);
+
+            assert!(!full.is_empty());
+            assert_eq!(pruned.vertex_count(), full.vertex_count());
+            assert_eq!(triangles(&pruned), triangles(&full));
+        }
+    }
+}
+
+#[test]
+fn pruning_skips_empty_space() {
+    let sphere = Sphere { center: Vec3::new(100.3, 90.7, 120.1), radius: 20.0, samples: Cell::new(0) };
+    let grid = Grid::cube(256);
+
+    let mesh = marching_cubes_pruned(grid, &sphere, Isolevel::default(), Topology::Classic, Normals::Gradient { epsilon: 1 });
+
+    assert!(!mesh.is_empty());
+    assert!(sphere.samples.get() < 257 * 257 * 257 / 50, "{}", sphere.samples.get());
+
+    for (&position, &normal) in mesh.positions.iter().zip(&mesh.normals) {
+        let outwards = (Vec3::from(position) - sphere.center).normalize();
+        assert!(outwards.dot(normal.into()) > 0.99);
+    }
+}
+
+// A plane through a corner shared by eight boxes, as near as its offset
+// can be rounded. Sampled at double precision, so that samples are the
+// exact values rounded once, and can land on either side of the corner.
+struct Plane {
+    normal: [f32; 3],
+    offset: f32,
+}
+
+impl ScalarField for Plane {
+    fn sample(&self, x: f32, y: f32, z: f32) -> f32 {
+        let [a, b, c] = self.normal.map(f64::from);
+        (a * x as f64 + b * y as f64 + c * z as f64 + self.offset as f64) as f32
+    }
+}
+
+impl IntervalField for Plane {
+    fn interval(&self, x: Interval, y: Interval, z: Interval) -> Interval {
+        let [a, b, c] = self.normal;
+        x * a + y * b + z * c + self.offset
+    }
+}
+
+#[test]
+fn pruning_keeps_a_surface_through_a_box_corner() {
+    let grid = Grid::cube(16);
+
+    for normal in [[0.1, 0.1, 0.1], [0.1, 0.1, 0.3], [0.1, 0.2, 0.3], [0.1, 0.2, 1.0]] {
+        let plane = Plane { normal, offset: -8.0 * (normal[0] + normal[1] + normal[2]) };
+
+        for isolevel in [Isolevel::new(0.0, Inside::Below), Isolevel::new(0.0, Inside::Above)] {
+            let pruned = marching_cubes_pruned(grid, &plane, isolevel, Topology::Classic, Normals::Area);
+            let full = marching_cubes(grid, &plane, isolevel, Topology::Classic, Normals::Area);
+
+            assert_eq!(triangles(&pruned), triangles(&full), "{:?} {:?}", normal, isolevel.inside);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use glam::{Vec2, Vec3};
+
+use marching_cubes::marching_squares::{contour_lines, filled_region, marching_squares, Ambiguity, Grid2d, PixelGrid, Polyline};
+use marching_cubes::{Grid, Inside, Isolevel, MeshData, VoxelGrid};
+
+const CENTER: Vec2 = Vec2::new(4.3, 3.8);
+
+fn circle(x: f32, y: f32) -> f32 {
+    Vec2::new(x, y).distance(CENTER) - 2.6
+}
+
+fn grid() -> Grid2d {
+    Grid2d::new([20, 18]).with_origin([0.1, -0.2]).with_spacing([0.5, 0.5])
+}
+
+// Positive for counter-clockwise lines.
+fn signed_area(polyline: &Polyline) -> f32 {
+    let points = &polyline.points;
+
+    (0..points.len())
+        .map(|i| {
+            let (a, b) = (points[i], points[(i + 1) % points.len()]);
+            a[0] * b[1] - b[0] * a[1]
+        })
+        .sum::<f32>()
+        / 2.0
+}
+
+// Positive for triangles wound like the 3D meshers' with a +z normal.
+fn mesh_area(mesh: &MeshData) -> f32 {
+    mesh.indices
+        .chunks_exact(3)
+        .map(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|k| Vec3::from(mesh.positions[triangle[k] as usize]));
+            (c - a).cross(b - a).z / 2.0
+        })
+        .sum()
+}
+
+#[test]
+fn circle_is_one_counter_clockwise_loop() {
+    let lines = marching_squares(grid(), &circle, Isolevel::default(), Ambiguity::Separate);
+
+    assert_eq!(lines.len(), 1);
+    assert!(lines[0].closed);
+
+    for &point in &lines[0].points {
+        assert!((Vec2::from(point).distance(CENTER) - 2.6).abs() < 0.05, "{:?}", point);
+    }
+
+    let area = signed_area(&lines[0]);
+    assert!((area - std::f32::consts::PI * 2.6 * 2.6).abs() < 0.2, "{}", area);
+}
+
+#[test]
+fn holes_run_clockwise() {
+    let outside = |x, y| -circle(x, y);
+    let lines = marching_squares(grid(), &outside, Isolevel::default(), Ambiguity::Separate);
+
+    assert_eq!(lines.len(), 1);
+    assert!(lines[0].closed);
+    assert!(signed_area(&lines[0]) < 0.0);
+
+    let above = marching_squares(grid(), &circle, Isolevel::new(0.0, Inside::Above), Ambiguity::Separate);
+    assert_eq!(lines, above);
+}
+
+#[test]
+fn lines_leaving_the_grid_are_open() {
+    let half_plane = |x: f32, y: f32| x + 0.5 * y - 5.0;
+    let lines = marching_squares(grid(), &half_plane, Isolevel::default(), Ambiguity::Separate);
+
+    assert_eq!(lines.len(), 1);
+    assert!(!lines[0].closed);
+
+    // The inside, towards -x, is on the left.
+    let points = &lines[0].points;
+    let direction = Vec2::from(points[points.len() - 1]) - Vec2::from(points[0]);
+    assert!(direction.perp().dot(Vec2::new(-1.0, -0.5)) > 0.0);
+
+    for pair in points.windows(2) {
+        assert!(half_plane(pair[0][0], pair[0][1]).abs() < 1e-4);
+        assert!(Vec2::from(pair[1]).distance(pair[0].into()) < 1.0);
+    }
+}
+
+#[test]
+fn decider_joins_saddles_that_are_inside() {
+    // Two inside corners on one diagonal, with an inside saddle point.
+    let pixel_grid = PixelGrid::new(Grid2d::square(1), vec![-1.0, 0.5, 0.5, -1.0]);
+
+    let separate = filled_region(&pixel_grid, Isolevel::default(), Ambiguity::Separate);
+    let joined = filled_region(&pixel_grid, Isolevel::default(), Ambiguity::AsymptoticDecider);
+
+    assert_eq!(separate.triangle_count(), 2);
+    assert_eq!(joined.triangle_count(), 4);
+    assert!(mesh_area(&joined) > mesh_area(&separate));
+
+    let separate = contour_lines(&pixel_grid, Isolevel::default(), Ambiguity::Separate);
+    let joined = contour_lines(&pixel_grid, Isolevel::default(), Ambiguity::AsymptoticDecider);
+
+    assert_eq!(separate.len(), 2);
+    assert_eq!(joined.len(), 2);
+    assert_ne!(separate, joined);
+
+    // With an outside saddle point, the decider keeps them apart as well.
+    let pixel_grid = PixelGrid::new(Grid2d::square(1), vec![-0.5, 1.0, 1.0, -0.5]);
+
+    assert_eq!(
+        contour_lines(&pixel_grid, Isolevel::default(), Ambiguity::Separate),
+        contour_lines(&pixel_grid, Isolevel::default(), Ambiguity::AsymptoticDecider),
+    );
+}
+
+#[test]
+fn filled_region_matches_its_outline() {
+    let pixel_grid = PixelGrid::from_scalar_field(grid(), &circle);
+
+    for ambiguity in [Ambiguity::Separate, Ambiguity::AsymptoticDecider] {
+        let lines = contour_lines(&pixel_grid, Isolevel::default(), ambiguity);
+        let region = filled_region(&pixel_grid, Isolevel::default(), ambiguity);
+
+        let outline: f32 = lines.iter().map(signed_area).sum();
+
+        assert!((mesh_area(&region) - outline).abs() < 1e-3);
+        assert!(region.normals.iter().all(|&normal| normal == [0.0, 0.0, 1.0]));
+    }
+}
+
+#[test]
+fn voxel_slices_match_the_field() {
+    let sphere = |x: f32, y: f32, z: f32| Vec3::new(x, y, z).distance(Vec3::new(4.3, 3.8, 2.9)) - 2.6;
+    let voxel_grid = VoxelGrid::from_scalar_field(Grid::new([20, 18, 12]).with_origin([0.1, -0.2, 0.5]).with_spacing([0.5; 3]), &sphere);
+
+    // z = 0.5 + 0.5 * 5
+    let slice = PixelGrid::from_voxel_slice(&voxel_grid, 2, 5);
+    let field = PixelGrid::from_scalar_field(grid(), &move |x, y| sphere(x, y, 3.0));
+
+    assert_eq!(slice.grid(), grid());
+    assert_eq!(
+        contour_lines(&slice, Isolevel::default(), Ambiguity::Separate),
+        contour_lines(&field, Isolevel::default(), Ambiguity::Separate),
+    );
+}
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use glam::Vec3;
+
+use marching_cubes::{marching_cubes, marching_tetrahedra::marching_tetrahedra, Grid, Inside, Isolevel, MeshData, Normals, Topology};
+
+const CENTER: Vec3 = Vec3::new(4.2, 3.9, 5.1);
+
+fn sphere(x: f32, y: f32, z: f32) -> f32 {
+    Vec3::new(x, y, z).distance(CENTER) - 2.7
+}
+
+fn grid() -> Grid {
+    Grid::new([18, 16, 20]).with_origin([0.3, 0.1, 0.2]).with_spacing([0.5, 0.5, 0.5])
+}
+
+fn area(mesh: &MeshData) -> f32 {
+    mesh.indices.chunks_exact(3).map(|triangle| {
+        let [a, b, c] = [0, 1, 2].map(|k| Vec3::from(mesh.positions[triangle[k] as usize]));
+        (b - a).cross(c - a).length() / 2.0
+    }).sum()
+}
+
+#[test]
+fn tetrahedra_mesh_is_closed() {
+    let mesh = marching_tetrahedra(grid(), &sphere, Isolevel::default(), Normals::Area);
+
+    let mut edges = HashMap::new();
+
+    for triangle in mesh.indices.chunks_exact(3) {
+        for k in 0..3 {
+            *edges.entry((triangle[k], triangle[(k + 1) % 3])).or_insert(0) += 1;
+        }
+    }
+
+    assert!(!edges.is_empty());
+
+    for (&(a, b), &count) in &edges {
+        assert_eq!(count, 1);
+        assert_eq!(edges.get(&(b, a)), Some(&1));
+    }
+}
+
+#[test]
+fn tetrahedra_normals_point_out() {
+    let mesh = marching_tetrahedra(grid(), &sphere, Isolevel::default(), Normals::Area);
+
+    for (&position, &normal) in mesh.positions.iter().zip(&mesh.normals) {
+        let outwards = (Vec3::from(position) - CENTER).normalize();
+        assert!(outwards.dot(normal.into()) > 0.8, "{:?}", position);
+    }
+}
+
+#[test]
+fn tetrahedra_agree_with_marching_cubes() {
+    let tetrahedra = marching_tetrahedra(grid(), &sphere, Isolevel::default(), Normals::Area);
+    let cubes = marching_cubes(grid(), &sphere, Isolevel::default(), Topology::Classic, Normals::Area);
+
+    // Both cut the edges of the cubes at the same points, the tetrahedra
+    // the diagonals as well.
+    for &position in &cubes.positions {
+        let found = tetrahedra.positions.iter().any(|&other| Vec3::from(other).abs_diff_eq(position.into(), 1e-5));
+        assert!(found, "{:?}", position);
+    }
+
+    assert!(tetrahedra.vertex_count() > cubes.vertex_count());
+    assert!((area(&tetrahedra) / area(&cubes) - 1.0).abs() < 0.02);
+}
+
+#[test]
+fn tetrahedra_follow_the_inside() {
+    let inverted = |x, y, z| -sphere(x, y, z);
+
+    let below = marching_tetrahedra(grid(), &sphere, Isolevel::default(), Normals::Area);
+    let above = marching_tetrahedra(grid(), &inverted, Isolevel::new(0.0, Inside::Above), Normals::Area);
+
+    assert_eq!(below, above);
+}
@@ -0,0 +1,352 @@
+use std::collections::{BTreeSet, HashMap};
+
+use marching_cubes::analysis::analyze;
+use marching_cubes::march_tables::{FACES, POINTS};
+use marching_cubes::{marching_cubes, marching_cubes_parallel, Grid, Inside, Isolevel, MeshData, Normals, Topology};
+
+// One set of corner values (in the order of `march_tables::POINTS`) for
+// each of the 33 subcases, with the number of pieces and the Euler
+// characteristic of the surface in the cube. Which of a pair of mirror
+// images is called 11 and which 14, or 12.2 and 12.3, differs between
+// papers.
+const SUBCASES: [(&str, [f32; 8], (usize, i64)); 33] = [
+    ("0", [-0.93, -0.09, -0.94, -0.99, -0.99, -0.92, -0.98, -1.0], (0, 0)),
+    ("1", [-0.6, 0.26, 0.73, 0.03, 0.02, 0.77, 0.93, 0.53], (1, 1)),
+    ("2", [-0.29, 0.18, 0.41, -0.03, -0.84, -0.54, -0.21, -0.54], (1, 1)),
+    ("3.1", [0.23, -1.0, -0.34, -0.9, -1.0, -0.42, -0.59, 0.05], (2, 2)),
+    ("3.2", [0.88, 0.56, 0.06, -0.99, 0.64, 0.97, -0.98, 0.08], (1, 1)),
+    ("4.1.1", [-0.99, 0.02, -0.99, -0.96, -0.99, -0.96, -0.91, 0.92], (2, 2)),
+    ("4.1.2", [0.03, 0.45, 0.18, -0.74, 0.18, -0.92, 0.22, 0.14], (1, 0)),
+    ("5", [0.69, 0.92, 0.31, -0.12, 0.26, 0.59, -0.32, -0.92], (1, 1)),
+    ("6.1.1", [0.98, -0.09, 1.01, 0.24, 0.27, 0.95, -0.27, -0.65], (2, 2)),
+    ("6.1.2", [-1.0, 0.14, 0.12, 0.08, -0.29, 0.72, -0.93, 0.64], (1, 0)),
+    ("6.2", [0.88, -0.03, 0.87, -0.1, -0.43, -0.45, 0.5, -0.71], (1, 1)),
+    ("7.1", [-0.86, 0.23, -0.9, 0.15, -0.5, -1.02, 0.17, -0.78], (3, 3)),
+    ("7.2", [0.02, -0.68, 0.82, 0.62, -0.82, 0.87, -0.22, 0.99], (2, 2)),
+    ("7.3", [0.13, 0.33, 0.8, -0.4, -0.93, 0.4, -0.83, 0.8], (1, 1)),
+    ("7.4.1", [0.02, -0.99, 0.96, -0.96, -0.99, 0.98, 0.94, 0.95], (2, 2)),
+    ("7.4.2", [0.96, -0.97, 0.94, -0.62, -0.7, 0.91, -0.72, -0.99], (1, 0)),
+    ("8", [-0.47, 0.55, 0.05, -0.85, -0.37, 0.64, 0.16, -0.73], (1, 1)),
+    ("9", [0.36, -0.69, -0.93, -0.16, 1.01, 0.26, -0.16, 0.45], (1, 1)),
+    ("10.1.1", [0.06, 0.04, -0.96, -0.89, -0.9, -0.92, 0.04, 0.22], (2, 2)),
+    ("10.1.2", [0.37, 0.29, -0.02, -1.01, -0.09, -0.86, 0.34, 0.54], (1, 0)),
+    ("10.2", [-0.79, 0.02, 0.87, -0.03, 0.05, -0.73, -0.2, 0.86], (1, 1)),
+    ("11", [0.98, 0.97, 0.95, -0.92, 0.95, -0.02, -0.99, -0.98], (1, 1)),
+    ("12.1.1", [-0.66, 0.02, -0.96, -0.25, 0.13, -0.89, 0.16, 0.45], (2, 2)),
+    ("12.1.2", [-0.37, -0.04, 0.92, 0.03, 0.65, -0.16, 0.06, -0.71], (1, 0)),
+    ("12.2", [-0.03, -1.01, -0.98, 0.51, 1.02, 0.92, 0.19, -0.78], (1, 1)),
+    ("12.3", [0.13, -0.84, 0.68, 0.6, -0.97, 0.88, -0.12, -0.68], (1, 1)),
+    ("13.1", [1.0, -0.47, 0.87, -0.21, -0.8, 1.02, -0.17, 0.82], (4, 4)),
+    ("13.2", [0.77, -0.49, 0.38, -0.02, -0.02, 0.35, -1.0, 1.0], (3, 3)),
+    ("13.3", [-0.37, 0.33, -0.63, 0.05, 0.8, -0.42, 0.48, -0.49], (2, 2)),
+    ("13.4", [0.61, -0.63, 0.99, -0.65, -0.94, 0.64, -0.81, 0.76], (1, 1)),
+    ("13.5.1", [-0.87, 0.59, -0.09, 0.61, 0.31, -0.89, 0.72, -0.67], (3, 3)),
+    ("13.5.2", [0.06, -0.93, 0.96, -0.92, -0.92, 1.0, -0.9, 0.99], (2, 1)),
+    ("14", [0.99, 0.97, -0.99, -0.09, 0.99, -0.93, -0.99, 0.99], (1, 1)),
+];
+
+// Small xorshift generator, so that the fields are the same on every run.
+struct Random(u32);
+
+impl Random {
+    fn next(&mut self) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        (self.0 >> 8) as f32 / (1 << 24) as f32
+    }
+}
+
+// Samples stored x-major, looked up at integer grid positions.
+fn sampled(size: usize, values: Vec<f32>) -> impl Fn(f32, f32, f32) -> f32 {
+    move |x, y, z| values[x as usize + y as usize * size + z as usize * size * size]
+}
+
+// A 4x4x4 sample grid that is outside everywhere but at the corners of the
+// middle cube, which take `corners` (in the order of `march_tables::POINTS`).
+// The surface can't reach the border of the grid, so it must be closed.
+fn middle_cube(corners: [f32; 8]) -> impl Fn(f32, f32, f32) -> f32 {
+    let mut values = vec![1.0; 64];
+
+    for (i, (x, y, z)) in marching_cubes::march_tables::POINTS.into_iter().enumerate() {
+        values[(x + 1) + (y + 1) * 4 + (z + 1) * 16] = corners[i];
+    }
+
+    sampled(4, values)
+}
+
+// Noise with a border that is outside, so the surface must be closed.
+fn closed_noise(size: usize, random: &mut Random) -> impl Fn(f32, f32, f32) -> f32 {
+    let values = (0..size * size * size)
+        .map(|i| {
+            let (x, y, z) = (i % size, i / size % size, i / size / size);
+
+            if [x, y, z].iter().any(|&c| c == 0 || c == size - 1) {
+                1.0
+            } else {
+                random.next() * 2.0 - 1.0
+            }
+        })
+        .collect();
+
+    sampled(size, values)
+}
+
+// A closed, consistently oriented surface uses every edge between two
+// vertices exactly once in each direction.
+fn is_watertight(mesh: &MeshData) -> bool {
+    let mut edges = HashMap::new();
+
+    for triangle in mesh.indices.chunks_exact(3) {
+        for k in 0..3 {
+            let (a, b) = (triangle[k], triangle[(k + 1) % 3]);
+
+            if a == b {
+                return false;
+            }
+
+            *edges.entry((a, b)).or_insert(0) += 1;
+        }
+    }
+
+    edges.iter().all(|(&(a, b), &count)| count == 1 && edges.get(&(b, a)) == Some(&1))
+}
+
+// Number of connected pieces of the surface.
+fn components(mesh: &MeshData) -> usize {
+    let mut parents: Vec<usize> = (0..mesh.vertex_count()).collect();
+
+    fn find(parents: &mut [usize], i: usize) -> usize {
+        let mut root = i;
+        while parents[root] != root {
+            root = parents[root];
+        }
+        parents[i] = root;
+        root
+    }
+
+    for triangle in mesh.indices.chunks_exact(3) {
+        for k in 1..3 {
+            let (a, b) = (find(&mut parents, triangle[0] as usize), find(&mut parents, triangle[k] as usize));
+            parents[a] = b;
+        }
+    }
+
+    (0..mesh.vertex_count()).filter(|&i| find(&mut parents, i) == i).count()
+}
+
+// The trilinear interpolant of the corner values of a unit cube.
+fn trilinear(corners: [f32; 8]) -> impl Fn(f32, f32, f32) -> f32 {
+    move |x, y, z| {
+        let weight = |t: f32, corner: usize| if corner == 1 { t } else { 1.0 - t };
+
+        POINTS.iter().zip(corners).map(|(&(cx, cy, cz), value)| weight(x, cx) * weight(y, cy) * weight(z, cz) * value).sum()
+    }
+}
+
+// The number of pieces and the Euler characteristic of a mesh.
+fn topology(mesh: &MeshData) -> (usize, i64) {
+    let report = analyze(mesh);
+
+    (report.components, report.euler_characteristic)
+}
+
+// The topology of the surface the trilinear interpolant of the corners
+// has in the cube, from a fine mesh of it.
+fn trilinear_topology(corners: [f32; 8]) -> (usize, i64) {
+    let grid = Grid::cube(16).with_spacing([1.0 / 16.0; 3]);
+
+    topology(&marching_cubes(grid, &trilinear(corners), Isolevel::default(), Topology::Classic, Normals::Area))
+}
+
+// The subcase of a cube, up to rotations and swapping inside and outside:
+// which corners are inside, and which diagonal of each ambiguous face is
+// joined by the interpolant.
+fn subcase(corners: [f32; 8]) -> (usize, Vec<[usize; 2]>) {
+    let config = (0..8).fold(0, |config, i| config | ((corners[i] < 0.0) as usize) << i);
+
+    let joined: Vec<[usize; 2]> = FACES
+        .iter()
+        .filter_map(|&(face, _)| {
+            let [a, b, c, d] = face.map(|i| corners[i]);
+
+            if (a < 0.0) != (c < 0.0) || (b < 0.0) != (d < 0.0) || (a < 0.0) == (b < 0.0) {
+                return None;
+            }
+
+            // The sign of the saddle point of the face tells which of its
+            // diagonals is joined.
+            let saddle = (a * c - b * d) / (a + c - b - d);
+
+            Some(if (saddle < 0.0) == (a < 0.0) { [face[0], face[2]] } else { [face[1], face[3]] })
+        })
+        .collect();
+
+    let corner = |[x, y, z]: [usize; 3]| POINTS.iter().position(|&point| point == (x, y, z)).unwrap();
+
+    // Permuting the axes evenly and flipping an even number of them, or
+    // the other way around, is a rotation.
+    let rotations = [[0, 1, 2], [1, 2, 0], [2, 0, 1], [0, 2, 1], [1, 0, 2], [2, 1, 0]]
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, axes)| (0..8usize).filter(move |flips| (flips.count_ones() % 2 == 0) == (i < 3)).map(move |flips| (axes, flips)))
+        .map(|(axes, flips)| {
+            POINTS.map(|(x, y, z)| {
+                let point = [x, y, z];
+                corner([0, 1, 2].map(|k| point[axes[k]] ^ (flips >> k & 1)))
+            })
+        });
+
+    rotations
+        .flat_map(|rotation| {
+            [0, 255].map(|swap| {
+                let config = (0..8).fold(0, |rotated, i| rotated | (config >> i & 1) << rotation[i]) ^ swap;
+                let mut joined: Vec<[usize; 2]> = joined
+                    .iter()
+                    .map(|&[a, b]| [rotation[a].min(rotation[b]), rotation[a].max(rotation[b])])
+                    .collect();
+
+                joined.sort();
+                (config, joined)
+            })
+        })
+        .min()
+        .unwrap()
+}
+
+#[test]
+fn mc33_is_watertight_for_every_configuration() {
+    let mut random = Random(0x2545_f491);
+
+    for config in 0..256 {
+        for _ in 0..64 {
+            // Vary the magnitudes a lot, so that ambiguous faces and cube
+            // interiors are resolved both ways.
+            let corners: [f32; 8] = std::array::from_fn(|i| {
+                let magnitude = 0.01 + random.next().powi(3);
+
+                if config & (1 << i) != 0 { -magnitude } else { magnitude }
+            });
+
+            let field = middle_cube(corners);
+            let mesh = marching_cubes(Grid::cube(3), &field, Isolevel::default(), Topology::Mc33, Normals::Area);
+
+            assert!(is_watertight(&mesh), "config {:#010b}, corners {:?}", config, corners);
+
+            let field = move |x, y, z| -field(x, y, z);
+            let isolevel = Isolevel::new(0.0, Inside::Above);
+            let flipped = marching_cubes(Grid::cube(3), &field, isolevel, Topology::Mc33, Normals::Area);
+
+            assert_eq!(mesh.indices, flipped.indices);
+        }
+    }
+}
+
+#[test]
+fn mc33_is_watertight_on_noise() {
+    let mut random = Random(0x9e37_79b9);
+
+    for _ in 0..16 {
+        let field = closed_noise(12, &mut random);
+        let mesh = marching_cubes(Grid::cube(11), &field, Isolevel::default(), Topology::Mc33, Normals::Area);
+
+        assert!(!mesh.is_empty());
+        assert!(is_watertight(&mesh));
+    }
+}
+
+#[test]
+fn mc33_tunnels_between_opposite_corners() {
+    // Two opposite inside corners, joined through the middle of the cube
+    // when the outside corners are only barely outside.
+    let corners = |outside| [-1.0, outside, outside, outside, outside, outside, -1.0, outside];
+
+    let tunnel = middle_cube(corners(0.1));
+    let mesh = marching_cubes(Grid::cube(3), &tunnel, Isolevel::default(), Topology::Mc33, Normals::Area);
+
+    assert!(is_watertight(&mesh));
+    assert_eq!(components(&mesh), 1);
+
+    let classic = marching_cubes(Grid::cube(3), &tunnel, Isolevel::default(), Topology::Classic, Normals::Area);
+
+    assert_eq!(components(&classic), 2);
+
+    let separate = middle_cube(corners(1.0));
+    let mesh = marching_cubes(Grid::cube(3), &separate, Isolevel::default(), Topology::Mc33, Normals::Area);
+
+    assert!(is_watertight(&mesh));
+    assert_eq!(components(&mesh), 2);
+}
+
+#[test]
+fn mc33_matches_classic_without_ambiguities() {
+    let sphere = |x: f32, y: f32, z: f32| (x - 8.0).powi(2) + (y - 8.0).powi(2) + (z - 8.0).powi(2) - 36.0;
+
+    let classic = marching_cubes(Grid::cube(16), &sphere, Isolevel::default(), Topology::Classic, Normals::Area);
+    let mc33 = marching_cubes(Grid::cube(16), &sphere, Isolevel::default(), Topology::Mc33, Normals::Area);
+
+    assert_eq!(classic, mc33);
+}
+
+#[test]
+fn mc33_parallel_matches_serial() {
+    let mut random = Random(0x1234_5678);
+    let field = closed_noise(12, &mut random);
+    let values: Vec<f32> = (0..12 * 12 * 12)
+        .map(|i| field((i % 12) as f32, (i / 12 % 12) as f32, (i / 144) as f32))
+        .collect();
+    let field = sampled(12, values);
+
+    let serial = marching_cubes(Grid::cube(11), &field, Isolevel::default(), Topology::Mc33, Normals::Area);
+    let parallel = marching_cubes_parallel(Grid::cube(11), &field, Isolevel::default(), Topology::Mc33, Normals::Area, 3);
+
+    assert_eq!(serial, parallel);
+}
+
+#[test]
+fn mc33_resolves_every_subcase() {
+    let mut subcases = BTreeSet::new();
+
+    for (name, corners, expected) in SUBCASES {
+        let mesh = marching_cubes(Grid::cube(1), &trilinear(corners), Isolevel::default(), Topology::Mc33, Normals::Area);
+
+        assert_eq!(trilinear_topology(corners), expected, "reference for {}", name);
+        assert_eq!(topology(&mesh), expected, "subcase {}", name);
+
+        // Both the subcase and its topology tell the table entries apart.
+        assert!(subcases.insert((subcase(corners), expected)), "subcase {} is in the table twice", name);
+    }
+
+    let cases: BTreeSet<usize> = subcases.iter().map(|((config, _), _)| *config).collect();
+
+    assert_eq!(cases.len(), 15);
+}
+
+#[test]
+fn mc33_finds_body_saddles_of_nearly_bilinear_cubes() {
+    // Case 6.1.1, with a cubic term so small that the saddle points of
+    // the interpolant are found around a point far outside the cube.
+    let corners = [-0.51163566, -0.98240393, 0.026722087, 0.8877799, -0.17098132, 0.3384615, -0.112756506, -0.23081978];
+    let mesh = marching_cubes(Grid::cube(1), &trilinear(corners), Isolevel::default(), Topology::Mc33, Normals::Area);
+
+    assert_eq!(trilinear_topology(corners), (2, 2));
+    assert_eq!(topology(&mesh), (2, 2));
+}
+
+#[test]
+fn mc33_resolves_distances_below_single_precision() {
+    for (name, corners, _) in SUBCASES {
+        let mesh = marching_cubes(Grid::cube(1), &trilinear(corners), Isolevel::default(), Topology::Mc33, Normals::Area);
+
+        // The same cube at double precision, scaled down until every corner
+        // is closer to the level than single precision can tell from zero.
+        let tiny = move |x: f64, y: f64, z: f64| trilinear(corners)(x as f32, y as f32, z as f32) as f64 * 1e-50;
+        let tiny_mesh = marching_cubes(Grid::<f64>::cube(1), &tiny, Isolevel::default(), Topology::Mc33, Normals::Area);
+
+        assert_eq!(tiny_mesh.indices, mesh.indices, "subcase {}", name);
+
+        for (tiny, position) in tiny_mesh.positions.iter().zip(&mesh.positions) {
+            assert!((0..3).all(|axis| (tiny[axis] - position[axis] as f64).abs() < 1e-5), "subcase {}", name);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use glam::Vec3;
+
+use marching_cubes::voxel_grid::BLOCK_SIZE;
+use marching_cubes::{marching_cubes, surface_nets::surface_net, transvoxel, Grid, Inside, Isolevel, Normals, Topology, Transitions, VoxelGrid, Voxels};
+
+fn grid() -> Grid {
+    Grid::new([37, 22, 30]).with_origin([-0.5, 0.25, 1.0]).with_spacing([0.5, 0.75, 0.5])
+}
+
+// A small ball in a mostly empty volume.
+fn ball(x: f32, y: f32, z: f32) -> f32 {
+    Vec3::new(x, y, z).distance(Vec3::new(3.1, 6.2, 4.4)) - 1.7
+}
+
+fn wavy(x: f32, y: f32, z: f32) -> f32 {
+    (x * 1.3).sin() + (y * 0.8).cos() * (z * 1.1).sin() - 0.3
+}
+
+#[test]
+fn pyramid_bounds_the_samples() {
+    let voxel_grid = VoxelGrid::from_scalar_field(grid(), &wavy);
+    let pyramid = voxel_grid.min_max_pyramid();
+    let [dx, dy, dz] = voxel_grid.dimensions();
+
+    let top = pyramid.levels() - 1;
+    assert_eq!(pyramid.blocks(top), [1, 1, 1]);
+    assert_eq!(pyramid.blocks(0), [10, 6, 8]);
+
+    let mut expected = (f32::INFINITY, f32::NEG_INFINITY);
+
+    for z in 0..dz {
+        for y in 0..dy {
+            for x in 0..dx {
+                let value = voxel_grid.read(x, y, z);
+                expected = (expected.0.min(value), expected.1.max(value));
+
+                // Every block holding the point bounds it, at every level.
+                for level in 0..pyramid.levels() {
+                    let side = BLOCK_SIZE << level;
+                    let blocks = pyramid.blocks(level);
+
+                    let block = [x, y, z].map(|c| c / side);
+                    let block = std::array::from_fn(|axis| block[axis].min(blocks[axis] - 1));
+
+                    let (min, max) = pyramid.range(level, block);
+                    assert!(min <= value && value <= max);
+                }
+            }
+        }
+    }
+
+    assert_eq!(pyramid.range(top, [0, 0, 0]), expected);
+}
+
+#[test]
+fn active_blocks_hold_every_crossing() {
+    for isolevel in [Isolevel::default(), Isolevel::new(0.5, Inside::Above)] {
+        let voxel_grid = VoxelGrid::from_scalar_field(grid(), &ball);
+        let active_blocks = voxel_grid.min_max_pyramid().active_blocks(isolevel);
+        let size = grid().size;
+
+        let mut skipped = 0;
+
+        for z in 0..size[2] {
+            for y in 0..size[1] {
+                let row: Vec<usize> = active_blocks.row(y, z, size[0]).collect();
+
+                for x in 0..size[0] {
+                    assert_eq!(row.contains(&x), active_blocks.contains((x, y, z)));
+
+                    if !row.contains(&x) {
+                        skipped += 1;
+
+                        let corners = marching_cubes::march_tables::POINTS
+                            .map(|(x0, y0, z0)| isolevel.is_inside(voxel_grid.read(x + x0, y + y0, z + z0)));
+                        assert!(corners.iter().all(|&inside| inside == corners[0]));
+                    }
+                }
+            }
+        }
+
+        // Most of the volume is empty.
+        assert!(skipped > size[0] * size[1] * size[2] * 3 / 4);
+    }
+}
+
+#[test]
+fn skipping_keeps_the_output() {
+    // Transvoxel without transitions visits every cube.
+    for field in [&ball as &dyn Fn(f32, f32, f32) -> f32, &wavy] {
+        let skipped = marching_cubes(grid(), field, Isolevel::default(), Topology::Classic, Normals::Area);
+        let visited = transvoxel(grid(), field, Isolevel::default(), Transitions::default(), Normals::Area);
+
+        assert!(!skipped.is_empty());
+        assert_eq!(skipped, visited);
+    }
+
+    let mesh = surface_net(grid(), &ball, Isolevel::default());
+    assert!(mesh.positions.iter().all(|&[x, y, z]| ball(x, y, z).abs() < 0.5));
+    assert!(!mesh.is_empty());
+}
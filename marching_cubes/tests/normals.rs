@@ -0,0 +1,98 @@
+use glam::Vec3;
+
+use marching_cubes::{marching_cubes, marching_cubes_parallel, Grid, Inside, Isolevel, MeshData, Normals, Topology};
+
+const CENTER: Vec3 = Vec3::new(5.0, 4.0, 6.0);
+
+fn sphere(x: f32, y: f32, z: f32) -> f32 {
+    Vec3::new(x, y, z).distance(CENTER) - 3.5
+}
+
+fn grid() -> Grid {
+    Grid::new([20, 18, 14]).with_origin([0.5, -0.5, 1.0]).with_spacing([0.5, 0.5, 0.75])
+}
+
+// The smallest cosine between a vertex normal and the direction from the
+// center of the sphere to the vertex.
+fn worst_alignment(mesh: &MeshData) -> f32 {
+    mesh.positions
+        .iter()
+        .zip(mesh.normals.iter())
+        .map(|(&position, &normal)| (Vec3::from(position) - CENTER).normalize().dot(normal.into()))
+        .fold(1.0, f32::min)
+}
+
+#[test]
+fn normals_point_out_of_a_sphere() {
+    for normals in [Normals::Area, Normals::Angle, Normals::Gradient { epsilon: 1 }, Normals::Gradient { epsilon: 2 }] {
+        let mesh = marching_cubes(grid(), &sphere, Isolevel::default(), Topology::Classic, normals);
+
+        assert!(!mesh.is_empty());
+        assert!(worst_alignment(&mesh) > 0.9, "{:?}: {}", normals, worst_alignment(&mesh));
+    }
+}
+
+#[test]
+fn gradient_normals_are_closer_than_face_normals() {
+    let gradient = marching_cubes(grid(), &sphere, Isolevel::default(), Topology::Classic, Normals::Gradient { epsilon: 1 });
+    let area = marching_cubes(grid(), &sphere, Isolevel::default(), Topology::Classic, Normals::Area);
+
+    assert!(worst_alignment(&gradient) > 0.99);
+    assert!(worst_alignment(&gradient) > worst_alignment(&area));
+}
+
+#[test]
+fn gradient_normals_are_exact_for_linear_fields() {
+    // Differences of a linear field don't depend on their distance, so
+    // one-sided ones at the border of the grid are exact as well.
+    let plane = |x: f32, y: f32, z: f32| x + 2.0 * y - z - 4.0;
+    let expected = Vec3::new(1.0, 2.0, -1.0).normalize();
+
+    for epsilon in [1, 3] {
+        let mesh = marching_cubes(grid(), &plane, Isolevel::default(), Topology::Classic, Normals::Gradient { epsilon });
+
+        assert!(!mesh.is_empty());
+
+        for &normal in &mesh.normals {
+            assert!(Vec3::from(normal).abs_diff_eq(expected, 1e-4), "{:?}", normal);
+        }
+    }
+}
+
+#[test]
+fn gradient_normals_follow_the_inside() {
+    let inverted = |x, y, z| -sphere(x, y, z);
+    let normals = Normals::Gradient { epsilon: 1 };
+
+    let below = marching_cubes(grid(), &sphere, Isolevel::default(), Topology::Classic, normals);
+    let above = marching_cubes(grid(), &inverted, Isolevel::new(0.0, Inside::Above), Topology::Classic, normals);
+
+    assert_eq!(below.normals, above.normals);
+}
+
+#[test]
+fn gradient_normals_cover_center_vertices() {
+    let corners = [-1.0, 0.1, 0.1, 0.1, 0.1, 0.1, -1.0, 0.1];
+    let mut values = vec![1.0; 64];
+
+    for (i, (x, y, z)) in marching_cubes::march_tables::POINTS.into_iter().enumerate() {
+        values[(x + 1) + (y + 1) * 4 + (z + 1) * 16] = corners[i];
+    }
+
+    let field = move |x: f32, y: f32, z: f32| values[x as usize + y as usize * 4 + z as usize * 16];
+    let mesh = marching_cubes(Grid::cube(3), &field, Isolevel::default(), Topology::Mc33, Normals::Gradient { epsilon: 1 });
+
+    for &normal in &mesh.normals {
+        assert!((Vec3::from(normal).length() - 1.0).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn parallel_normals_match_serial() {
+    for normals in [Normals::Angle, Normals::Gradient { epsilon: 2 }] {
+        let serial = marching_cubes(grid(), &sphere, Isolevel::default(), Topology::Classic, normals);
+        let parallel = marching_cubes_parallel(grid(), &sphere, Isolevel::default(), Topology::Classic, normals, 4);
+
+        assert_eq!(serial, parallel);
+    }
+}
@@ -0,0 +1,34 @@
+use marching_cubes::{marching_cubes, marching_cubes_parallel, Grid, Inside, Isolevel, Normals, Topology};
+
+fn grid() -> Grid {
+    Grid::new([19, 16, 9]).with_origin([0.5, -1.0, 0.25]).with_spacing([0.5, 0.6, 0.7])
+}
+
+fn waves(x: f32, y: f32, z: f32) -> f32 {
+    (x * 1.1).sin() + (y * 0.8 + 0.3).cos() * (z * 1.4).sin() + (x * 0.5 - z * 0.9).cos() * 0.6
+}
+
+#[test]
+fn parallel_output_is_identical_to_serial() {
+    let isolevel = Isolevel::new(0.1, Inside::Below);
+    let layers = grid().size[2];
+
+    for topology in [Topology::Classic, Topology::Mc33] {
+        for normals in [Normals::Area, Normals::Angle, Normals::Gradient { epsilon: 1 }, Normals::Gradient { epsilon: 2 }] {
+            let serial = marching_cubes(grid(), &waves, isolevel, topology, normals);
+            assert!(!serial.is_empty());
+
+            // Including more threads than there are layers of cubes.
+            for threads in [1, 2, 3, 4, 7, layers, layers + 1, 32] {
+                let parallel = marching_cubes_parallel(grid(), &waves, isolevel, topology, normals, threads);
+                assert!(parallel == serial, "{:?}, {:?}, {} threads", topology, normals, threads);
+            }
+        }
+    }
+}
+
+#[test]
+fn parallel_output_of_an_empty_field_is_empty() {
+    let mesh = marching_cubes_parallel(grid(), &|_, _, _| 1.0, Isolevel::default(), Topology::Classic, Normals::Area, 4);
+    assert!(mesh.is_empty());
+}
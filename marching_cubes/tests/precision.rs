@@ -0,0 +1,298 @@
+use glam::{Affine3A, DVec3, Quat, Vec3};
+
+use marching_cubes::dual_contouring::dual_contour;
+use marching_cubes::interval::{Interval, IntervalField};
+use marching_cubes::marching_tetrahedra::marching_tetrahedra;
+use marching_cubes::surface_nets::surface_net;
+use marching_cubes::{
+    extended_marching_cubes, marching_cubes, marching_cubes_capped, marching_cubes_disjointed, marching_cubes_interpolation,
+    marching_cubes_parallel, marching_cubes_pruned,
+    marching_cubes_voxels, marching_cubes_welded, transvoxel, Chunks, EditableVoxels, Features, Grid, IncrementalMesh,
+    Inside, Isolevel, MeshData, Normals, Real, ScalarField, SparseVoxelGrid, Topology, Transitions, VoxelGrid,
+};
+
+// Kilometre-scale map coordinates, where neighbouring f32 values are half
+// a unit apart.
+const FAR: [f64; 3] = [4_512_000.0, 6_100_000.0, 2_300.0];
+
+fn sphere(center: DVec3) -> impl Fn(f64, f64, f64) -> f64 {
+    move |x, y, z| DVec3::new(x, y, z).distance(center) - 5.3
+}
+
+// A sphere in either precision, for the meshers that take a field by type.
+struct Ball<T> {
+    center: [T; 3],
+    radius: T,
+}
+
+impl<T: Real> ScalarField<T> for Ball<T> {
+    fn sample(&self, x: T, y: T, z: T) -> T {
+        let [dx, dy, dz] = [x - self.center[0], y - self.center[1], z - self.center[2]];
+        (dx * dx + dy * dy + dz * dz).sqrt() - self.radius
+    }
+}
+
+impl<T: Real> IntervalField<T> for Ball<T> {
+    fn interval(&self, x: Interval<T>, y: Interval<T>, z: Interval<T>) -> Interval<T> {
+        ((x - self.center[0]).square() + (y - self.center[1]).square() + (z - self.center[2]).square()).sqrt() - self.radius
+    }
+}
+
+fn near_grid() -> Grid {
+    Grid::new([16, 15, 17]).with_spacing([1.0, 1.25, 1.0])
+}
+
+fn far_grid() -> Grid<f64> {
+    Grid::new([16, 15, 17]).with_origin(FAR).with_spacing([1.0, 1.25, 1.0])
+}
+
+// The largest distance between the vertices of `near` and the ones of
+// `far` moved back to the origin.
+fn largest_offset(near: &MeshData, far: &MeshData<f64>) -> f64 {
+    near.positions
+        .iter()
+        .zip(&far.positions)
+        .map(|(&near, &far)| (DVec3::from(far) - DVec3::from(FAR)).distance(near.map(f64::from).into()))
+        .fold(0.0, f64::max)
+}
+
+// The largest distance between the vertices of two meshes at the same
+// place.
+fn largest_offset_from(single: &MeshData, double: &MeshData<f64>) -> f64 {
+    single.positions
+        .iter()
+        .zip(&double.positions)
+        .map(|(&single, &double)| DVec3::from(double).distance(single.map(f64::from).into()))
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn far_meshes_match_meshes_at_the_origin() {
+    let center = [8.1, 9.7, 8.4];
+    let near_sphere = move |x: f32, y: f32, z: f32| {
+        sphere(DVec3::from(center))(x as f64, y as f64, z as f64) as f32
+    };
+    let far_sphere = sphere(DVec3::from(FAR) + DVec3::from(center));
+
+    for topology in [Topology::Classic, Topology::Mc33] {
+        for normals in [Normals::Area, Normals::Gradient { epsilon: 1 }] {
+            let near = marching_cubes(near_grid(), &near_sphere, Isolevel::default(), topology, normals);
+            let far = marching_cubes(far_grid(), &far_sphere, Isolevel::default(), topology, normals);
+
+            assert!(!near.is_empty());
+            assert_eq!(near.indices, far.indices);
+            assert!(largest_offset(&near, &far) < 1e-4, "{:?}: {}", topology, largest_offset(&near, &far));
+
+            for (a, b) in near.normals.iter().zip(&far.normals) {
+                assert!(glam::Vec3::from(*a).dot((*b).into()) > 0.9999);
+            }
+        }
+    }
+
+    let near = surface_net(near_grid(), &near_sphere, Isolevel::default());
+    let far = surface_net(far_grid(), &far_sphere, Isolevel::default());
+
+    assert_eq!(near.indices, far.indices);
+    assert!(largest_offset(&near, &far) < 1e-4);
+
+    // A larger sphere, which leaves the grid and is capped.
+    let near = marching_cubes_capped(near_grid(), &near_sphere, Isolevel::new(3.0, Inside::Below), Topology::Classic, Normals::Area);
+    let far = marching_cubes_capped(far_grid(), &far_sphere, Isolevel::new(3.0, Inside::Below), Topology::Classic, Normals::Area);
+
+    assert_eq!(near.indices, far.indices);
+    assert!(largest_offset(&near, &far) < 1e-4);
+}
+
+#[test]
+fn single_precision_jitters_far_from_the_origin() {
+    // Rounding the vertices of a far sphere to f32 moves them by a good
+    // part of the spacing, while in f64 they stay where they belong.
+    let center = DVec3::from(FAR) + DVec3::new(8.1, 9.7, 8.4);
+    let far = marching_cubes(far_grid(), &sphere(center), Isolevel::default(), Topology::Classic, Normals::Area);
+
+    let worst = far.positions
+        .iter()
+        .map(|&position| (DVec3::from(position).distance(center) - 5.3).abs())
+        .fold(0.0, f64::max);
+
+    // Linear interpolation of a sphere is off by less than a tenth of the
+    // spacing at this radius.
+    assert!(worst < 0.1, "{}", worst);
+
+    let rounded = far.positions
+        .iter()
+        .map(|&position| (DVec3::from(position.map(|c| c as f32 as f64)).distance(center) - 5.3).abs())
+        .fold(0.0, f64::max);
+
+    assert!(rounded > 0.2, "{}", rounded);
+}
+
+#[test]
+fn far_meshes_have_bounds_and_transform_like_meshes_at_the_origin() {
+    let center = [8.1, 9.7, 8.4];
+    let near_sphere = move |x: f32, y: f32, z: f32| {
+        sphere(DVec3::from(center))(x as f64, y as f64, z as f64) as f32
+    };
+    let far_sphere = sphere(DVec3::from(FAR) + DVec3::from(center));
+
+    let mut near = marching_cubes(near_grid(), &near_sphere, Isolevel::default(), Topology::Classic, Normals::Area);
+    let mut far = marching_cubes(far_grid(), &far_sphere, Isolevel::default(), Topology::Classic, Normals::Area);
+
+    let (near_min, near_max) = near.bounds().unwrap();
+    let (far_min, far_max) = far.bounds().unwrap();
+
+    for axis in 0..3 {
+        assert!((far_min[axis] - FAR[axis] - near_min[axis] as f64).abs() < 1e-4);
+        assert!((far_max[axis] - FAR[axis] - near_max[axis] as f64).abs() < 1e-4);
+    }
+
+    let transform = Affine3A::from_scale_rotation_translation(
+        Vec3::new(1.5, -1.0, 2.0),
+        Quat::from_rotation_y(0.7),
+        Vec3::new(3.0, -2.0, 1.0),
+    );
+
+    // In single precision, it is the same as transforming with glam.
+    let expected: Vec<[f32; 3]> = near.positions.iter().map(|&p| transform.transform_point3(p.into()).into()).collect();
+
+    near.transform(transform);
+    assert_eq!(near.positions, expected);
+
+    // In double precision, the far mesh keeps its shape where it ends up.
+    let mut origin = MeshData::<f64>::new(vec![FAR], vec![[0.0; 3]], Vec::new());
+
+    origin.transform(transform);
+    far.transform(transform);
+
+    // Both are moved by the transform of where they started from.
+    let worst = near.positions
+        .iter()
+        .zip(&far.positions)
+        .map(|(&near, &far)| {
+            let near = DVec3::from(near.map(f64::from)) - DVec3::from(transform.translation.to_array().map(f64::from));
+            (DVec3::from(far) - DVec3::from(origin.positions[0])).distance(near)
+        })
+        .fold(0.0, f64::max);
+
+    assert_eq!(near.indices, far.indices);
+    assert!(worst < 1e-4, "{}", worst);
+}
+
+#[test]
+fn every_mesher_places_far_vertices_like_at_the_origin() {
+    let center = [8.1, 9.7, 8.4];
+    let near_sphere = move |x: f32, y: f32, z: f32| {
+        sphere(DVec3::from(center))(x as f64, y as f64, z as f64) as f32
+    };
+    let far_sphere = sphere(DVec3::from(FAR) + DVec3::from(center));
+
+    let check_within = |name: &str, near: MeshData, far: MeshData<f64>, tolerance: f64| {
+        assert!(!near.is_empty(), "{}", name);
+        assert_eq!(near.indices, far.indices, "{}", name);
+        assert!(largest_offset(&near, &far) < tolerance, "{}: {}", name, largest_offset(&near, &far));
+    };
+    let check = |name: &str, near: MeshData, far: MeshData<f64>| check_within(name, near, far, 1e-4);
+
+    check(
+        "parallel",
+        marching_cubes_parallel(near_grid(), &near_sphere, Isolevel::default(), Topology::Mc33, Normals::Area, 3),
+        marching_cubes_parallel(far_grid(), &far_sphere, Isolevel::default(), Topology::Mc33, Normals::Area, 3),
+    );
+
+    let chunk = Grid { size: [8, 5, 6], ..near_grid() };
+    check(
+        "welded",
+        marching_cubes_welded(Chunks::new(chunk, [2, 3, 3]), &near_sphere, Isolevel::default(), Topology::Classic, Normals::Area),
+        marching_cubes_welded(Chunks::new(Grid { size: [8, 5, 6], ..far_grid() }, [2, 3, 3]), &far_sphere, Isolevel::default(), Topology::Classic, Normals::Area),
+    );
+
+    check(
+        "tetrahedra",
+        marching_tetrahedra(near_grid(), &near_sphere, Isolevel::default(), Normals::Area),
+        marching_tetrahedra(far_grid(), &far_sphere, Isolevel::default(), Normals::Area),
+    );
+
+    // The tangent planes come from differences of the field over a
+    // thousandth of a cell, which single precision samples near the origin
+    // only get to about a thousandth.
+    check_within(
+        "dual contouring",
+        dual_contour(near_grid(), &near_sphere, Isolevel::default()),
+        dual_contour(far_grid(), &far_sphere, Isolevel::default()),
+        1e-2,
+    );
+
+    check_within(
+        "extended",
+        extended_marching_cubes(near_grid(), &near_sphere, Isolevel::default(), Features::default()),
+        extended_marching_cubes(far_grid(), &far_sphere, Isolevel::default(), Features::default()),
+        1e-2,
+    );
+
+    // A larger sphere, which crosses the face with transition cells.
+    let transitions = Transitions::new([true, false, false, false, false, false]);
+    check(
+        "transvoxel",
+        transvoxel(Grid { size: [16, 16, 18], ..near_grid() }, &near_sphere, Isolevel::new(3.0, Inside::Below), transitions, Normals::Area),
+        transvoxel(Grid { size: [16, 16, 18], ..far_grid() }, &far_sphere, Isolevel::new(3.0, Inside::Below), transitions, Normals::Area),
+    );
+
+    let near_ball = Ball { center: center.map(|c| c as f32), radius: 5.3 };
+    let far_ball = Ball { center: (DVec3::from(FAR) + DVec3::from(center)).to_array(), radius: 5.3 };
+    check(
+        "pruned",
+        marching_cubes_pruned(near_grid(), &near_ball, Isolevel::default(), Topology::Classic, Normals::Area),
+        marching_cubes_pruned(far_grid(), &far_ball, Isolevel::default(), Topology::Classic, Normals::Area),
+    );
+
+    check(
+        "sparse",
+        marching_cubes_voxels(&SparseVoxelGrid::from_scalar_field(near_grid(), &near_sphere), Isolevel::default(), Topology::Classic, Normals::Area),
+        marching_cubes_voxels(&SparseVoxelGrid::from_scalar_field(far_grid(), &far_sphere), Isolevel::default(), Topology::Classic, Normals::Area),
+    );
+
+    // Hollowing out the middle of the sphere patches both meshes alike.
+    let mut near_voxels = EditableVoxels::new(VoxelGrid::from_scalar_field(near_grid(), &near_sphere));
+    let mut far_voxels = EditableVoxels::new(VoxelGrid::from_scalar_field(far_grid(), &far_sphere));
+
+    let mut near = IncrementalMesh::new(&near_voxels, Isolevel::default(), Topology::Classic, Normals::Area);
+    let mut far = IncrementalMesh::new(&far_voxels, Isolevel::default(), Topology::Classic, Normals::Area);
+
+    near_voxels.write(8, 8, 8, 1.0);
+    far_voxels.write(8, 8, 8, 1.0);
+    near.update(&mut near_voxels);
+    far.update(&mut far_voxels);
+
+    check("incremental", near.mesh().clone(), far.mesh().clone());
+}
+
+#[test]
+fn unindexed_meshers_take_double_precision_fields() {
+    let center = [8.1, 9.7, 8.4];
+    let single = move |x: f32, y: f32, z: f32| sphere(DVec3::from(center))(x as f64, y as f64, z as f64) as f32;
+    let double = sphere(DVec3::from(center));
+
+    let close = |single: &MeshData, double: &MeshData<f64>| {
+        assert!(!single.is_empty());
+        assert_eq!(single.vertex_count(), double.vertex_count());
+        assert!(largest_offset_from(single, double) < 1e-4, "{}", largest_offset_from(single, double));
+
+        for (&a, &b) in single.normals.iter().zip(&double.normals) {
+            assert!(Vec3::from(a).distance(b.into()) < 1e-3);
+        }
+    };
+
+    close(
+        &marching_cubes_interpolation(18, &single, Isolevel::default(), 0.8, 0.5),
+        &marching_cubes_interpolation(18, &double, Isolevel::default(), 0.8, 0.5),
+    );
+
+    let single = marching_cubes_disjointed(18, &single, Isolevel::default());
+    let double = marching_cubes_disjointed(18, &double, Isolevel::default());
+
+    assert_eq!(single.len(), double.len());
+
+    for (single, double) in single.iter().zip(&double).filter(|(single, _)| !single.is_empty()) {
+        close(single, double);
+    }
+}
@@ -0,0 +1,164 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use glam::Vec3;
+
+use marching_cubes::interval::{Interval, IntervalField};
+use marching_cubes::sparse_voxel_grid::LEAF_SIZE;
+use marching_cubes::surface_nets::surface_net;
+use marching_cubes::{
+    marching_cubes, marching_cubes_chunks, marching_cubes_parallel, marching_cubes_pruned, Chunks, Grid, Isolevel,
+    Normals, ScalarField, SparseVoxelGrid, Topology, VoxelGrid, Voxels,
+};
+
+fn grid() -> Grid {
+    Grid::new([14, 11, 13]).with_origin([-0.5, 1.0, 0.25]).with_spacing([0.5, 0.6, 0.5])
+}
+
+fn sphere(x: f32, y: f32, z: f32) -> f32 {
+    Vec3::new(x, y, z).distance(Vec3::new(3.1, 4.3, 3.6)) - 2.4
+}
+
+// The same sphere, sampled a row at a time. Counts how often it is
+// sampled either way.
+#[derive(Default)]
+struct Rows {
+    points: AtomicUsize,
+    rows: AtomicUsize,
+}
+
+impl ScalarField for Rows {
+    fn sample(&self, x: f32, y: f32, z: f32) -> f32 {
+        self.points.fetch_add(1, Ordering::Relaxed);
+        sphere(x, y, z)
+    }
+
+    fn sample_row(&self, grid: Grid, [x, y, z]: [usize; 3], values: &mut [f32]) {
+        self.rows.fetch_add(1, Ordering::Relaxed);
+
+        let [_, wy, wz] = grid.to_world([0.0, y as f32, z as f32]);
+
+        for (i, value) in values.iter_mut().enumerate() {
+            let wx = grid.origin[0] + (x + i) as f32 * grid.spacing[0];
+            *value = sphere(wx, wy, wz);
+        }
+    }
+}
+
+impl Rows {
+    fn counts(&self) -> (usize, usize) {
+        (self.points.swap(0, Ordering::Relaxed), self.rows.swap(0, Ordering::Relaxed))
+    }
+}
+
+// The same sphere again, sampled a block at a time, and bounded nowhere,
+// so that pruning keeps every box.
+#[derive(Default)]
+struct Blocks {
+    points: AtomicUsize,
+    blocks: AtomicUsize,
+}
+
+impl ScalarField for Blocks {
+    fn sample(&self, x: f32, y: f32, z: f32) -> f32 {
+        self.points.fetch_add(1, Ordering::Relaxed);
+        sphere(x, y, z)
+    }
+
+    fn sample_block(&self, grid: Grid, start: [usize; 3], dimensions: [usize; 3], values: &mut [f32]) {
+        self.blocks.fetch_add(1, Ordering::Relaxed);
+        sphere.sample_block(grid, start, dimensions, values);
+    }
+}
+
+impl IntervalField for Blocks {
+    fn interval(&self, _: Interval, _: Interval, _: Interval) -> Interval {
+        Interval::new(f32::NEG_INFINITY, f32::INFINITY)
+    }
+}
+
+impl Blocks {
+    fn counts(&self) -> (usize, usize) {
+        (self.points.swap(0, Ordering::Relaxed), self.blocks.swap(0, Ordering::Relaxed))
+    }
+}
+
+#[test]
+fn meshers_sample_grids_a_row_at_a_time() {
+    let rows = Rows::default();
+    let [_, size_y, size_z] = grid().dimensions();
+
+    let expected = marching_cubes(grid(), &sphere, Isolevel::default(), Topology::Classic, Normals::Area);
+    let mesh = marching_cubes(grid(), &rows, Isolevel::default(), Topology::Classic, Normals::Area);
+
+    assert_eq!(mesh, expected);
+    assert_eq!(rows.counts(), (0, size_y * size_z));
+
+    let mesh = marching_cubes_parallel(grid(), &rows, Isolevel::default(), Topology::Classic, Normals::Area, 3);
+
+    assert_eq!(mesh, expected);
+    assert_eq!(rows.counts(), (0, size_y * size_z));
+
+    let mesh = surface_net(grid(), &rows, Isolevel::default());
+
+    assert_eq!(mesh, surface_net(grid(), &sphere, Isolevel::default()));
+    assert_eq!(rows.counts(), (0, size_y * size_z));
+
+    let chunks = Chunks::new(grid(), [2, 2, 2]);
+    let meshes = marching_cubes_chunks(chunks, &rows, Isolevel::default(), Topology::Classic, Normals::Area);
+
+    assert_eq!(meshes, marching_cubes_chunks(chunks, &sphere, Isolevel::default(), Topology::Classic, Normals::Area));
+    assert_eq!(rows.counts().0, 0);
+}
+
+#[test]
+fn blocks_are_sampled_in_grid_order() {
+    let start = [3, 2, 5];
+    let dimensions = [4, 3, 2];
+
+    let mut values = vec![0.0; 24];
+    sphere.sample_block(grid(), start, dimensions, &mut values);
+
+    for (i, &value) in values.iter().enumerate() {
+        let point = [start[0] + i % 4, start[1] + i / 4 % 3, start[2] + i / 12];
+        let [x, y, z] = grid().to_world(point.map(|c| c as f32));
+
+        assert_eq!(value, sphere(x, y, z));
+    }
+
+    // A block that spans a whole grid is its `VoxelGrid`.
+    let voxel_grid = VoxelGrid::from_scalar_field(grid(), &sphere);
+    let [dx, dy, dz] = grid().dimensions();
+
+    let mut values = vec![0.0; dx * dy * dz];
+    sphere.sample_block(grid(), [0; 3], grid().dimensions(), &mut values);
+
+    for (i, &value) in values.iter().enumerate() {
+        assert_eq!(value, voxel_grid.read(i % dx, i / dx % dy, i / (dx * dy)));
+    }
+}
+
+#[test]
+fn leaves_and_pruned_boxes_are_sampled_a_block_at_a_time() {
+    let blocks = Blocks::default();
+    let dense = VoxelGrid::from_scalar_field(grid(), &sphere);
+    let [dx, dy, dz] = grid().dimensions();
+
+    let sparse = SparseVoxelGrid::from_scalar_field(grid(), &blocks);
+
+    for i in 0..dx * dy * dz {
+        let [x, y, z] = [i % dx, i / dx % dy, i / (dx * dy)];
+        assert_eq!(sparse.read(x, y, z), dense.read(x, y, z));
+    }
+
+    let leaves: usize = grid().dimensions().iter().map(|d| d.div_ceil(LEAF_SIZE)).product();
+    assert_eq!(blocks.counts(), (0, leaves));
+
+    let mesh = marching_cubes_pruned(grid(), &blocks, Isolevel::default(), Topology::Classic, Normals::Area);
+    let expected = marching_cubes(grid(), &sphere, Isolevel::default(), Topology::Classic, Normals::Area);
+
+    assert_eq!(mesh.vertex_count(), expected.vertex_count());
+
+    let (points, boxes) = blocks.counts();
+    assert_eq!(points, 0);
+    assert!(boxes > 1);
+}
@@ -0,0 +1,110 @@
+use glam::Vec3;
+
+use marching_cubes::dual_contouring::dual_contour;
+use marching_cubes::smoothing::{smooth, smooth_onto_field, Smoothing, Weights};
+use marching_cubes::surface_nets::surface_net;
+use marching_cubes::{marching_cubes, Grid, Isolevel, MeshData, Normals, Topology};
+
+const CENTER: Vec3 = Vec3::new(8.1, 7.7, 8.4);
+
+fn sphere(x: f32, y: f32, z: f32) -> f32 {
+    Vec3::new(x, y, z).distance(CENTER) - 5.3
+}
+
+fn tilted_plane(x: f32, y: f32, z: f32) -> f32 {
+    y - 0.3 * x - 0.2 * z - 2.15
+}
+
+fn mean_radius(mesh: &MeshData) -> f32 {
+    mesh.positions.iter().map(|&p| Vec3::from(p).distance(CENTER)).sum::<f32>() / mesh.vertex_count() as f32
+}
+
+// Mean angle between the triangles and the sphere they approximate, either
+// way around.
+fn mean_deviation(mesh: &MeshData) -> f32 {
+    let angles: Vec<f32> = mesh.indices
+        .chunks_exact(3)
+        .map(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|k| Vec3::from(mesh.positions[triangle[k] as usize]));
+            let outwards = ((a + b + c) / 3.0 - CENTER).normalize();
+            (c - a).cross(b - a).normalize().dot(outwards).abs().min(1.0).acos()
+        })
+        .collect();
+
+    angles.iter().sum::<f32>() / angles.len() as f32
+}
+
+#[test]
+fn taubin_keeps_the_size_that_laplacian_loses() {
+    let mesh = marching_cubes(Grid::cube(16), &sphere, Isolevel::default(), Topology::Classic, Normals::Area);
+
+    let laplacian = smooth(&mesh, Smoothing::new(10));
+    let taubin = smooth(&mesh, Smoothing::new(10).with_mu(-0.53));
+
+    assert_eq!(laplacian.indices, mesh.indices);
+    assert_eq!(taubin.indices, mesh.indices);
+
+    assert!(mean_radius(&laplacian) < mean_radius(&mesh) - 0.2, "{}", mean_radius(&laplacian));
+    assert!((mean_radius(&taubin) - mean_radius(&mesh)).abs() < 0.05, "{}", mean_radius(&taubin));
+
+    for (&position, &normal) in taubin.positions.iter().zip(&taubin.normals) {
+        assert!((Vec3::from(position) - CENTER).normalize().dot(normal.into()) > 0.95);
+    }
+}
+
+#[test]
+fn projected_surface_nets_lose_their_steps() {
+    let mesh = surface_net(Grid::cube(16), &sphere, Isolevel::default());
+
+    for weights in [Weights::Uniform, Weights::Cotangent] {
+        let smoothing = Smoothing::new(10).with_weights(weights);
+        let smoothed = smooth_onto_field(&mesh, smoothing, Grid::cube(16), &sphere, Isolevel::default());
+
+        assert!(mean_deviation(&smoothed) < mean_deviation(&mesh) * 0.75, "{:?}: {} of {}", weights, mean_deviation(&smoothed), mean_deviation(&mesh));
+
+        for (&[x, y, z], &normal) in smoothed.positions.iter().zip(&smoothed.normals) {
+            assert!(sphere(x, y, z).abs() < 1e-3, "{:?}: {}", weights, sphere(x, y, z));
+            assert!((Vec3::new(x, y, z) - CENTER).normalize().dot(normal.into()) > 0.999);
+        }
+    }
+}
+
+#[test]
+fn cotangent_weights_keep_a_plane_flat_and_its_border_in_place() {
+    let mesh = marching_cubes(Grid::cube(8), &tilted_plane, Isolevel::default(), Topology::Classic, Normals::Area);
+    let smoothed = smooth(&mesh, Smoothing::new(20).with_weights(Weights::Cotangent));
+
+    let mut moved = 0;
+
+    for (&before, &after) in mesh.positions.iter().zip(&smoothed.positions) {
+        let [x, y, z] = after;
+        assert!(tilted_plane(x, y, z).abs() < 1e-4);
+
+        let on_border = before.iter().any(|&c| c == 0.0 || c == 8.0);
+
+        if on_border {
+            assert_eq!(before, after);
+        } else if before != after {
+            moved += 1;
+        }
+    }
+
+    assert!(moved > 0);
+}
+
+#[test]
+fn smoothed_normals_point_out_of_every_mesher() {
+    let meshes = [
+        marching_cubes(Grid::cube(16), &sphere, Isolevel::default(), Topology::Classic, Normals::Area),
+        surface_net(Grid::cube(16), &sphere, Isolevel::default()),
+        dual_contour(Grid::cube(16), &sphere, Isolevel::default()),
+    ];
+
+    for mesh in meshes {
+        let smoothed = smooth(&mesh, Smoothing::new(5));
+
+        for (&position, &normal) in smoothed.positions.iter().zip(&smoothed.normals) {
+            assert!((Vec3::from(position) - CENTER).normalize().dot(normal.into()) > 0.9);
+        }
+    }
+}
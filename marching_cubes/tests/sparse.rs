@@ -0,0 +1,110 @@
+use glam::Vec3;
+
+use marching_cubes::sparse_voxel_grid::LEAF_SIZE;
+use marching_cubes::surface_nets::{surface_net, surface_net_voxels};
+use marching_cubes::{marching_cubes, marching_cubes_voxels, Grid, Isolevel, Normals, SparseVoxelGrid, Topology, VoxelGrid, Voxels};
+
+fn grid() -> Grid {
+    Grid::new([45, 30, 38]).with_origin([-1.0, 0.5, -0.25]).with_spacing([0.25, 0.3, 0.25])
+}
+
+// A ball clamped to a band around its surface, so most of the volume is
+// one of two values.
+fn clamped_ball(x: f32, y: f32, z: f32) -> f32 {
+    (Vec3::new(x, y, z).distance(Vec3::new(4.1, 4.9, 4.3)) - 1.8).clamp(-0.25, 0.25)
+}
+
+#[test]
+fn sparse_reads_like_dense() {
+    let dense = VoxelGrid::from_scalar_field(grid(), &clamped_ball);
+    let sparse = SparseVoxelGrid::from_scalar_field(grid(), &clamped_ball);
+    let copy = SparseVoxelGrid::from_voxels(&dense);
+
+    assert_eq!(Voxels::dimensions(&sparse), dense.dimensions());
+    let [dx, dy, dz] = dense.dimensions();
+
+    for z in 0..dz {
+        for y in 0..dy {
+            for x in 0..dx {
+                assert_eq!(sparse.read(x, y, z), dense.read(x, y, z));
+                assert_eq!(copy.read(x, y, z), dense.read(x, y, z));
+            }
+        }
+    }
+
+    // Most leaves are entirely inside or outside of the band.
+    assert!(sparse.stored_samples() < dx * dy * dz / 4, "{}", sparse.stored_samples());
+}
+
+#[test]
+fn meshers_read_sparse_voxels() {
+    let sparse = SparseVoxelGrid::from_scalar_field(grid(), &clamped_ball);
+
+    for topology in [Topology::Classic, Topology::Mc33] {
+        for normals in [Normals::Area, Normals::Gradient { epsilon: 1 }] {
+            let expected = marching_cubes(grid(), &clamped_ball, Isolevel::default(), topology, normals);
+
+            assert!(!expected.is_empty());
+            assert_eq!(marching_cubes_voxels(&sparse, Isolevel::default(), topology, normals), expected);
+        }
+    }
+
+    let expected = surface_net(grid(), &clamped_ball, Isolevel::default());
+    assert!(!expected.is_empty());
+    assert_eq!(surface_net_voxels(&sparse, Isolevel::default()), expected);
+}
+
+#[test]
+fn writes_split_and_merge_nodes() {
+    let grid = Grid::cube(20);
+    let mut sparse = SparseVoxelGrid::new(grid, 1.0);
+    assert_eq!(sparse.stored_samples(), 1);
+
+    // A small solid box in an empty world.
+    for z in 5..9 {
+        for y in 6..10 {
+            for x in 7..11 {
+                sparse.write(x, y, z, -1.0);
+            }
+        }
+    }
+
+    assert!(sparse.stored_samples() > 1);
+    assert!(sparse.stored_samples() < 21 * 21 * 21);
+
+    for z in 0..21 {
+        for y in 0..21 {
+            for x in 0..21 {
+                let inside = (7..11).contains(&x) && (6..10).contains(&y) && (5..9).contains(&z);
+                assert_eq!(sparse.read(x, y, z), if inside { -1.0 } else { 1.0 });
+            }
+        }
+    }
+
+    let mesh = marching_cubes_voxels(&sparse, Isolevel::default(), Topology::Classic, Normals::Area);
+    assert!(!mesh.is_empty());
+
+    // Clearing the box again leaves a single value.
+    for z in 5..9 {
+        for y in 6..10 {
+            for x in 7..11 {
+                sparse.write(x, y, z, 1.0);
+            }
+        }
+    }
+
+    assert_eq!(sparse.stored_samples(), 1);
+
+    // A whole leaf of one value, other than its neighbours', is one value.
+    let mut sparse = SparseVoxelGrid::new(grid, 1.0);
+
+    for z in 0..LEAF_SIZE {
+        for y in 0..LEAF_SIZE {
+            for x in 0..LEAF_SIZE {
+                sparse.write(x, y, z, 2.0);
+            }
+        }
+    }
+
+    assert_eq!(sparse.stored_samples(), 15);
+}
@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+use glam::Vec3;
+
+use marching_cubes::{marching_cubes, transvoxel, Grid, Isolevel, MeshData, Normals, Topology, Transitions};
+
+// A sphere centred on face `face` of an 8^3 block of unit cells.
+fn sphere_on_face(face: usize) -> impl Fn(f32, f32, f32) -> f32 {
+    let mut center = Vec3::splat(4.0);
+    center[face / 2] = if face.is_multiple_of(2) { 0.0 } else { 8.0 };
+
+    move |x, y, z| Vec3::new(x, y, z).distance(center) - 3.3
+}
+
+// The block across face `face`, with cells twice as large.
+fn coarse_neighbour(face: usize) -> Grid {
+    let mut origin = [0.0; 3];
+    origin[face / 2] = if face.is_multiple_of(2) { -8.0 } else { 8.0 };
+
+    Grid::cube(4).with_origin(origin).with_spacing([2.0; 3])
+}
+
+// Every directed edge of the welded meshes is matched by the opposite one.
+fn is_closed(meshes: &[&MeshData]) -> bool {
+    let mut welded = HashMap::new();
+    let mut edges = HashMap::new();
+
+    for mesh in meshes {
+        let ids: Vec<usize> = mesh.positions.iter().map(|&position| {
+            let key = position.map(|c| (c * 1000.0).round() as i64);
+            let next = welded.len();
+            *welded.entry(key).or_insert(next)
+        }).collect();
+
+        for triangle in mesh.indices.chunks_exact(3) {
+            for k in 0..3 {
+                let edge = (ids[triangle[k] as usize], ids[triangle[(k + 1) % 3] as usize]);
+                *edges.entry(edge).or_insert(0) += 1;
+            }
+        }
+    }
+
+    edges.iter().all(|(&(a, b), &count)| count == 1 && edges.get(&(b, a)) == Some(&1))
+}
+
+#[test]
+fn without_transitions_matches_marching_cubes() {
+    let grid = Grid::new([8, 6, 10]).with_origin([-1.0, 0.5, 2.0]).with_spacing([0.5, 1.0, 0.75]);
+    let field = |x: f32, y: f32, z: f32| (x * 0.9).sin() + (y * 0.7).cos() * (z * 0.5).sin() - 0.2;
+
+    let regular = marching_cubes(grid, &field, Isolevel::default(), Topology::Classic, Normals::Area);
+    let transvoxel = transvoxel(grid, &field, Isolevel::default(), Transitions::default(), Normals::Area);
+
+    assert!(!regular.is_empty());
+    assert_eq!(regular, transvoxel);
+}
+
+#[test]
+fn transition_faces_close_the_seam() {
+    for face in 0..6 {
+        let field = sphere_on_face(face);
+
+        let mut faces = [false; 6];
+        faces[face] = true;
+
+        let fine = transvoxel(Grid::cube(8), &field, Isolevel::default(), Transitions::new(faces), Normals::Area);
+        let coarse = marching_cubes(coarse_neighbour(face), &field, Isolevel::default(), Topology::Classic, Normals::Area);
+
+        assert!(is_closed(&[&fine, &coarse]), "face {}", face);
+    }
+}
+
+#[test]
+fn regular_faces_crack() {
+    for face in 0..6 {
+        let field = sphere_on_face(face);
+
+        let fine = transvoxel(Grid::cube(8), &field, Isolevel::default(), Transitions::default(), Normals::Area);
+        let coarse = marching_cubes(coarse_neighbour(face), &field, Isolevel::default(), Topology::Classic, Normals::Area);
+
+        assert!(!is_closed(&[&fine, &coarse]), "face {}", face);
+    }
+}
+
+#[test]
+fn transition_faces_meet_at_edges_and_corners() {
+    // A sphere around the corner at the origin, cut by three transition
+    // faces. The transition cells of neighbouring faces share the edges
+    // along which the faces meet.
+    let field = |x: f32, y: f32, z: f32| Vec3::new(x, y, z).length() - 5.3;
+    let transitions = Transitions::new([true, false, true, false, true, false]).with_width(0.25);
+
+    let mesh = transvoxel(Grid::cube(8), &field, Isolevel::default(), transitions, Normals::Area);
+
+    assert!(!mesh.is_empty());
+
+    for &position in &mesh.positions {
+        assert!(position.iter().all(|&c| (0.0..=8.0).contains(&c)), "{:?}", position);
+    }
+
+    // Normals still point out of the sphere through the squashed cells.
+    for (&position, &normal) in mesh.positions.iter().zip(&mesh.normals) {
+        assert!(Vec3::from(position).normalize().dot(normal.into()) > 0.5, "{:?}", position);
+    }
+}
+
+#[test]
+#[should_panic]
+fn odd_transition_faces_panic() {
+    let field = |x: f32, _: f32, _: f32| x - 2.5;
+
+    transvoxel(Grid::new([8, 7, 8]), &field, Isolevel::default(), Transitions::new([true, false, false, false, false, false]), Normals::Area);
+}
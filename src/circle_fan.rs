@@ -1,8 +1,10 @@
 use std::f32::consts::PI;
 
+use marching_cubes::MeshData;
+
 pub fn circle_fan(
     resolution: u32
-) -> (Vec<[f32; 3]>, Vec<[f32; 3]>, Vec<u32>) {
+) -> MeshData {
     let mut positions = Vec::new();
     let mut indices = Vec::new();
 
@@ -20,5 +22,5 @@ pub fn circle_fan(
     
     
     let normals = vec![[0.0,0.0,1.0]; (resolution + 1) as usize];
-    (positions, normals, indices)
+    MeshData::new(positions, normals, indices)
 }
\ No newline at end of file
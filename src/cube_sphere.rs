@@ -1,5 +1,7 @@
 use crate::normal_material::NormalMaterial;
 
+use marching_cubes::MeshData;
+
 use super::*;
 
 fn cube_to_sphere(v: Vec3) -> Vec3 {
@@ -10,7 +12,7 @@ fn cube_to_sphere(v: Vec3) -> Vec3 {
     )
 }
 
-fn generate_face(resolution: usize, local_y: Vec3) -> Mesh {
+fn generate_face(resolution: usize, local_y: Vec3) -> MeshData {
     let local_x = Vec3::new(local_y.y, local_y.z, local_y.x);
     let local_z = local_y.cross(local_x);
 
@@ -42,12 +44,7 @@ fn generate_face(resolution: usize, local_y: Vec3) -> Mesh {
         }
     }
 
-    let mut mesh = Mesh::new(bevy::render::render_resource::PrimitiveTopology::TriangleList);
-    mesh.set_indices(Some(bevy::render::mesh::Indices::U32(indices)));
-    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
-
-    mesh
+    MeshData::new(positions, normals, indices)
 }
 
 pub fn spawn_cube_sphere(
@@ -70,7 +67,7 @@ pub fn spawn_cube_sphere(
     .with_children(|parent| {
         for dir in dirs {
             parent.spawn_bundle(MaterialMeshBundle {
-                mesh: meshes.add(generate_face(16, dir)),
+                mesh: meshes.add(generate_face(16, dir).into()),
                 material: materials.add(NormalMaterial{}),
                 ..Default::default()
             });
@@ -1,5 +1,3 @@
-pub mod marching_cubes;
-pub mod surface_nets;
 pub mod showcase;
 pub mod visualization_of_marching_cubes_full;
 pub mod visualization_of_marching_cubes_zoom;
@@ -10,6 +8,8 @@ pub mod cube_sphere;
 
 use std::{f32::consts::TAU, env};
 
+use marching_cubes::surface_nets;
+
 pub use bevy::prelude::*;
 use bevy::render::{camera::{Projection, DepthCalculation, CameraProjection, ComputedCameraValues}, primitives::Frustum};
 
@@ -3,7 +3,7 @@ use crate::normal_material::NormalMaterial;
 use super::*;
 
 
-use bevy::{input::mouse::MouseMotion, render::{settings::{WgpuSettings, WgpuFeatures}, mesh::Indices}, pbr::wireframe::{WireframePlugin, WireframeConfig}, log::LogSettings, window::WindowMode};
+use bevy::{input::mouse::MouseMotion, render::settings::{WgpuSettings, WgpuFeatures}, pbr::wireframe::{WireframePlugin, WireframeConfig}, log::LogSettings, window::WindowMode};
 use noise::{NoiseFn, Perlin, Fbm};
 use stopwatch::Stopwatch;
 
@@ -134,11 +134,11 @@ fn update_surface_nets(
                 + 22.0
         };
 
-        let (positions, normals, indices) = surface_nets::surface_net(resolution, implicit_function);
+        let sw = Stopwatch::start_new();
+        let mesh_data = surface_nets::surface_net(marching_cubes::Grid::cube(resolution), implicit_function, marching_cubes::Isolevel::default());
+        println!("Surface nets took: {}ms", sw.elapsed_ms());
     
-        mesh.set_indices(Some(Indices::U32(indices)));
-        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        *mesh = mesh_data.into();
     }  
 }
 
@@ -164,12 +164,9 @@ fn surface_nets_mesh(
 ) {
     wireframe_config.global = true;
 
-    let (positions, normals, indices) = surface_nets::surface_net(RES, &implicit_function);
-
-    let mut mesh = Mesh::new(bevy::render::render_resource::PrimitiveTopology::TriangleList);
-    mesh.set_indices(Some(Indices::U32(indices)));
-    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    let sw = Stopwatch::start_new();
+    let mesh: Mesh = surface_nets::surface_net(marching_cubes::Grid::cube(RES), &implicit_function, marching_cubes::Isolevel::default()).into();
+    println!("Surface nets took: {}ms", sw.elapsed_ms());
 
     commands.spawn_bundle(MaterialMeshBundle {
         mesh: meshes.add(mesh),
@@ -193,38 +190,28 @@ pub fn marching_cubes_mesh(
     use std::sync::mpsc;
 
     let pool = ThreadPool::default();
-    let (tx, rx) = mpsc::channel::<(Mesh, Transform)>();
+    let (tx, rx) = mpsc::channel::<Mesh>();
 
     for cz in 0..CHUNK_RES {
         for cy in 0..CHUNK_RES {
             for cx in 0..CHUNK_RES {
                 let tx = tx.clone();
                 pool.execute(move || {
-                    let scalar_field = move |i: f32, j: f32, k: f32| -> f32 {
+                    let scalar_field = move |x: f32, y: f32, z: f32| -> f32 {
                         let scale = 1.0 / RES as f32;
-                        let (x, y, z) = (
-                            (i) * scale + cx as f32 - CHUNK_RES as f32 * 0.5,
-                            (j) * scale + cy as f32 - CHUNK_RES as f32 * 0.5,
-                            (k) * scale + cz as f32 - CHUNK_RES as f32 * 0.5,
-                        );
-                        let noise = noise_func.get([x as f64, y as f64, z as f64]) as f32;
+                        let noise = noise_func.get([(x * scale) as f64, (y * scale) as f64, (z * scale) as f64]) as f32;
     
                         noise
                     };
 
+                    let origin = Vec3::splat(-0.5 * (CHUNK_RES * RES) as f32);
+                    let chunks = marching_cubes::Chunks::new(marching_cubes::Grid::cube(RES).with_origin(origin.into()), [CHUNK_RES; 3]);
+
                     let sw = Stopwatch::start_new();
-                    let (positions, normals, indices) = marching_cubes::marching_cubes(RES, &scalar_field);
+                    let mesh: Mesh = marching_cubes::marching_cubes_chunk(chunks, [cx, cy, cz], &scalar_field, marching_cubes::Isolevel::default(), marching_cubes::Topology::Classic, marching_cubes::Normals::Area).into();
                     println!("{} / {}: Marching cubes took: {}ms", cx + cy * CHUNK_RES + cz * CHUNK_RES * CHUNK_RES + 1, CHUNK_RES * CHUNK_RES * CHUNK_RES, sw.elapsed_ms());
 
-                    let mut mesh = Mesh::new(bevy::render::render_resource::PrimitiveTopology::TriangleList);
-                    mesh.set_indices(Some(Indices::U32(indices)));
-                    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-                    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
-
-                    let position = Vec3::new(cx as f32, cy as f32, cz as f32) * RES as f32 - Vec3::splat(0.5 * (CHUNK_RES * RES) as f32);
-                    let scale = Vec3::ONE;
-
-                    tx.send((mesh, Transform::from_translation(position).with_scale(scale))).expect("awooga");
+                    tx.send(mesh).expect("awooga");
                 });
             }
         }
@@ -234,11 +221,10 @@ pub fn marching_cubes_mesh(
         ..Default::default()
     }).insert(Name::new("World"))
     .with_children(|builder| {
-        rx.iter().take(CHUNK_RES * CHUNK_RES * CHUNK_RES).for_each(|(mesh, transform)| {
+        rx.iter().take(CHUNK_RES * CHUNK_RES * CHUNK_RES).for_each(|mesh| {
             builder.spawn_bundle(MaterialMeshBundle {
                 mesh: meshes.add(mesh),
                 material: materials.add(NormalMaterial{}),
-                transform,
                 ..Default::default()
             });
         })
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::marching_cubes::march_tables;
+use marching_cubes::march_tables;
 
 #[derive(Component)]
 pub struct LookAtCamera;
@@ -1,8 +1,8 @@
-use crate::marching_cubes::march_tables;
+use marching_cubes::march_tables;
 
 use super::*;
 
-use bevy::{render::{mesh::Indices, once_cell::sync::Lazy}, log::LogSettings, window::WindowMode};
+use bevy::{render::once_cell::sync::Lazy, log::LogSettings, window::WindowMode};
 use bevy_inspector_egui::{WorldInspectorPlugin, Inspectable, RegisterInspectable};
 
 use crate::visualization_helper::*;
@@ -148,12 +148,7 @@ fn spawn_grid_points(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
-    let (positions, normals, indices) = circle_fan::circle_fan(12);
-
-    let mut mesh = Mesh::new(bevy::render::render_resource::PrimitiveTopology::TriangleList);
-    mesh.set_indices(Some(Indices::U32(indices)));
-    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    let mesh: Mesh = circle_fan::circle_fan(12).into();
 
     let shared_mesh = meshes.add(mesh);
 
@@ -267,27 +262,14 @@ fn spawn_grid_mesh(
     let entity = binding.insert(Name::new("Mesh"));
 
     entity.add_children(|parent| {
-        let positions_vec = marching_cubes::marching_cubes_disjointed(RES, &SCALAR_FIELD);
+        let mesh_data_vec = marching_cubes::marching_cubes_disjointed(RES, &SCALAR_FIELD, marching_cubes::Isolevel::default());
 
         for z in 0..RES {
             for y in 0..RES {
                 for x in 0..RES {
                     let idx = x + y * RES + z * RES * RES;
 
-                    let positions = positions_vec[idx].clone();
-
-                    let normals: Vec<[f32; 3]> = positions.chunks_exact(3).map(|p| {
-                        let p1: Vec3 = p[0].into();
-                        let p2: Vec3 = p[1].into();
-                        let p3: Vec3 = p[2].into();
-
-                        (p2 - p1).cross(p1 - p3).into()
-                    }).flat_map(|n| [n; 3])
-                    .collect();
-                
-                    let mut mesh = Mesh::new(bevy::render::render_resource::PrimitiveTopology::TriangleList);
-                    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-                    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+                    let mesh: Mesh = mesh_data_vec[idx].clone().into();
                 
                     parent.spawn_bundle(PbrBundle {
                         mesh: meshes.add(mesh),
@@ -368,10 +350,11 @@ fn interpolate_mesh_system(
     let t0 = timings.get_time_in_stage(TimeStage::InterpolateMesh as usize, time.seconds_since_startup() as f32);
     let t1 = timings.get_time_in_stage(TimeStage::NormalizeMesh as usize, time.seconds_since_startup() as f32);
 
-    let (positions, normals) = 
+    let mesh_data = 
         marching_cubes::marching_cubes_interpolation(
             RES, 
             SCALAR_FIELD, 
+            marching_cubes::Isolevel::default(),
             smoothstep(t0), 
             smoothstep(t1),
         );
@@ -383,8 +366,7 @@ fn interpolate_mesh_system(
 
         let mesh = meshes.get_mut(mesh_handle).unwrap();
 
-        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        *mesh = mesh_data.into();
     }
 }
 
@@ -1,8 +1,9 @@
 use super::*;
 
-use crate::{visualization_helper::*, marching_cubes::march_tables};
+use crate::visualization_helper::*;
+use marching_cubes::{march_tables, MeshData};
 
-use bevy::{render::mesh::Indices, log::LogSettings, window::WindowMode};
+use bevy::{log::LogSettings, window::WindowMode};
 use bevy_inspector_egui::WorldInspectorPlugin;
 
 use ttf2mesh::{Value, TTFFile};
@@ -95,12 +96,7 @@ fn spawn_points(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
 ) {
-    let (positions, normals, indices) = circle_fan::circle_fan(16);
-
-    let mut mesh = Mesh::new(bevy::render::render_resource::PrimitiveTopology::TriangleList);
-    mesh.set_indices(Some(Indices::U32(indices)));
-    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    let mesh: Mesh = circle_fan::circle_fan(16).into();
 
     let shared_mesh = meshes.add(mesh);
 
@@ -170,12 +166,7 @@ fn spawn_numbers(
 
             let num = ('0' as u8 + i as u8) as char;
 
-            let (positions, normals, indices) = mesh_from_char(&mut font, num);
-            
-            let mut mesh = Mesh::new(bevy::render::render_resource::PrimitiveTopology::TriangleList);
-            mesh.set_indices(Some(Indices::U32(indices)));
-            mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-            mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+            let mesh: Mesh = mesh_from_char(&mut font, num).into();
 
             let p = march_tables::POINTS[i];
 
@@ -203,12 +194,7 @@ fn spawn_numbers(
             if i < 10 {
                 let num = ('0' as u8 + i as u8) as char;
     
-                let (positions, normals, indices) = mesh_from_char(&mut font, num);
-            
-                let mut mesh = Mesh::new(bevy::render::render_resource::PrimitiveTopology::TriangleList);
-                mesh.set_indices(Some(Indices::U32(indices)));
-                mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-                mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+                let mesh: Mesh = mesh_from_char(&mut font, num).into();
 
                 let edge = march_tables::EDGES[i];
 
@@ -234,18 +220,8 @@ fn spawn_numbers(
             else {
                 let num = ('0' as u8 + (i & 1) as u8) as char;
 
-                let (positions0, normals0, indices0) = mesh_from_char(&mut font, '1');
-                let (positions1, normals1, indices1) = mesh_from_char(&mut font, num);
-
-                let mut mesh0 = Mesh::new(bevy::render::render_resource::PrimitiveTopology::TriangleList);
-                mesh0.set_indices(Some(Indices::U32(indices0)));
-                mesh0.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions0);
-                mesh0.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals0);
-
-                let mut mesh1 = Mesh::new(bevy::render::render_resource::PrimitiveTopology::TriangleList);
-                mesh1.set_indices(Some(Indices::U32(indices1)));
-                mesh1.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions1);
-                mesh1.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals1);
+                let mesh0: Mesh = mesh_from_char(&mut font, '1').into();
+                let mesh1: Mesh = mesh_from_char(&mut font, num).into();
 
                 let edge = march_tables::EDGES[i];
 
@@ -535,14 +511,13 @@ fn mesh_system(
 
     let mesh = meshes.get_mut(q_mesh.single()).unwrap();
 
-    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    *mesh = MeshData::unindexed(positions, normals).into();
 }
 
 fn mesh_from_char(
     font: &mut TTFFile,
     char: char,
-) -> (Vec<[f32; 3]>, Vec<[f32; 3]>, Vec<u32>) {
+) -> MeshData {
     let mut glyph = font.glyph_from_char(char).unwrap();
 
     let glyph_mesh = glyph.to_2d_mesh(ttf2mesh::Quality::High).unwrap();
@@ -566,7 +541,7 @@ fn mesh_from_char(
 
     let normals = vec![[0.0, 0.0, -1.0]; positions.len()];
 
-    (positions, normals, indices)
+    MeshData::new(positions, normals, indices)
 }
 
 fn camera_system (